@@ -0,0 +1,35 @@
+use crate::{Matrix, Gf2};
+use super::linear::LinearCode;
+
+impl LinearCode {
+    /// Hamming code of length 2^r - 1 and dimension 2^r - 1 - r. Column j of
+    /// H is j + 1 in binary, least significant bit in row 0, so a single error
+    /// at position j has syndrome j + 1.
+    pub fn hamming(r: usize) -> Self {
+        assert!(r >= 2, "Hamming codes need r >= 2, got {}", r);
+        Self::from_parity_check(hamming_parity_check(r)).expect("Hamming parity-check matrix has independent rows")
+    }
+
+    /// The [7, 4] Hamming code.
+    pub fn hamming_7_4() -> Self {
+        Self::hamming(3)
+    }
+
+    /// Hamming code with an overall parity bit appended: length 2^r, minimum
+    /// distance 4, so it corrects one error and detects two.
+    pub fn extended_hamming(r: usize) -> Self {
+        assert!(r >= 2, "Hamming codes need r >= 2, got {}", r);
+        let h = hamming_parity_check(r);
+        let n = h.cols + 1;
+        let mut rows: Vec<Vec<Gf2>> = h.data.into_iter().map(|mut row| { row.push(Gf2::new(0)); row }).collect();
+        rows.push(vec![Gf2::new(1); n]);
+        Self::from_parity_check(Matrix::from(rows)).expect("Extended Hamming parity-check matrix has independent rows")
+    }
+}
+
+fn hamming_parity_check(r: usize) -> Matrix<Gf2> {
+    let n = (1 << r) - 1;
+    Matrix::from((0..r).map(|bit| {
+        (0..n).map(|j| Gf2::new(((j + 1) >> bit & 1) as u64)).collect::<Vec<Gf2>>()
+    }).collect::<Vec<_>>())
+}
@@ -0,0 +1,214 @@
+use crate::types::Semiring;
+use crate::{Matrix, Vector, Gf2};
+
+// Largest n - k for which the syndrome table (2^(n-k) coset leaders) is built.
+const MAX_SYNDROME_BITS: usize = 16;
+
+// Largest k for which the minimum distance is found by enumerating codewords.
+const MAX_ENUMERATED_DIMENSION: usize = 24;
+
+/// Binary linear [n, k] code. Rows of `generator` (k x n) span the code,
+/// rows of `parity_check` ((n - k) x n) span its dual, so G H^T = 0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearCode {
+    pub generator: Matrix<Gf2>,
+    pub parity_check: Matrix<Gf2>,
+    // Columns where the rref of G has its pivots, and the transposed inverse
+    // of G restricted to them: the message is read back from those positions.
+    info_positions: Vec<usize>,
+    info_inverse: Matrix<Gf2>,
+    // Minimum-weight error pattern for every syndrome, indexed by its bits.
+    coset_leaders: Vec<Vector<Gf2>>,
+}
+
+impl LinearCode {
+    pub fn from_generator(generator: Matrix<Gf2>) -> Result<Self, String> {
+        let (rref, pivots) = reduced(&generator);
+        if pivots.len() < generator.rows {
+            return Err(format!("Generator rows are dependent: rank {} < {}", pivots.len(), generator.rows));
+        }
+        let parity_check = complement(&rref, &pivots);
+        Self::assemble(generator, parity_check, pivots)
+    }
+
+    /// The code whose codewords are exactly the vectors with H c = 0. `H` is
+    /// kept as given when its rows are independent, so its syndromes keep
+    /// their meaning.
+    pub fn from_parity_check(parity_check: Matrix<Gf2>) -> Result<Self, String> {
+        let (rref, pivots) = reduced(&parity_check);
+        if pivots.len() == parity_check.cols {
+            return Err("Parity-check matrix has full column rank, so the code is trivial".to_string());
+        }
+        let generator = complement(&rref, &pivots);
+        let (generator_rref, info_positions) = reduced(&generator);
+        let parity_check = if pivots.len() == parity_check.rows {
+            parity_check
+        } else {
+            complement(&generator_rref, &info_positions)
+        };
+        Self::assemble(generator, parity_check, info_positions)
+    }
+
+    fn assemble(generator: Matrix<Gf2>, parity_check: Matrix<Gf2>, info_positions: Vec<usize>) -> Result<Self, String> {
+        let info_cols: Vec<Vec<Gf2>> = generator.data.iter().map(|row| info_positions.iter().map(|&p| row[p]).collect()).collect();
+        let info_inverse = Matrix::from(info_cols).inverse()?.transpose();
+        let mut code = Self { generator, parity_check, info_positions, info_inverse, coset_leaders: Vec::new() };
+        code.coset_leaders = code.build_coset_leaders();
+        Ok(code)
+    }
+
+    /// Length n.
+    pub fn len(&self) -> usize {
+        self.generator.cols
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.generator.cols == 0
+    }
+
+    /// Dimension k.
+    pub fn dimension(&self) -> usize {
+        self.generator.rows
+    }
+
+    /// [I | P] up to a column permutation: the rref of G.
+    pub fn systematic_generator(&self) -> Matrix<Gf2> {
+        reduced(&self.generator).0
+    }
+
+    /// The rref of H, with its zero rows dropped.
+    pub fn systematic_parity_check(&self) -> Matrix<Gf2> {
+        let (rref, pivots) = reduced(&self.parity_check);
+        rref.submatrix(0, 0, pivots.len(), rref.cols)
+    }
+
+    /// m G, for a message of k bits.
+    pub fn encode(&self, message: &Vector<Gf2>) -> Vector<Gf2> {
+        assert_eq!(message.size(), self.dimension(), "Message must have {} bits, got {}", self.dimension(), message.size());
+        self.generator.transpose().mul_vec(message.clone())
+    }
+
+    pub fn syndrome(&self, word: &Vector<Gf2>) -> Vector<Gf2> {
+        assert_eq!(word.size(), self.len(), "Word must have {} bits, got {}", self.len(), word.size());
+        self.parity_check.mul_vec(word.clone())
+    }
+
+    pub fn is_codeword(&self, word: &Vector<Gf2>) -> bool {
+        self.syndrome(word).data.iter().all(|&s| s == Gf2::zero())
+    }
+
+    /// Nearest codeword by syndrome decoding: the received word minus the
+    /// lightest error pattern with the same syndrome.
+    pub fn correct(&self, received: &Vector<Gf2>) -> Result<Vector<Gf2>, String> {
+        if self.coset_leaders.is_empty() {
+            return Err(format!("Syndrome table is only built for n - k <= {}", MAX_SYNDROME_BITS));
+        }
+        let mut word = received.clone();
+        word.sub(self.coset_leaders[syndrome_index(&self.syndrome(received))].clone());
+        Ok(word)
+    }
+
+    /// The message bits of the nearest codeword.
+    pub fn decode(&self, received: &Vector<Gf2>) -> Result<Vector<Gf2>, String> {
+        let codeword = self.correct(received)?;
+        let info = Vector::from(self.info_positions.iter().map(|&p| codeword.data[p]).collect::<Vec<Gf2>>());
+        Ok(self.info_inverse.mul_vec(info))
+    }
+
+    /// Smallest weight of a nonzero codeword, by enumerating all 2^k - 1 of them.
+    pub fn minimum_distance(&self) -> Result<usize, String> {
+        let k = self.dimension();
+        if k > MAX_ENUMERATED_DIMENSION {
+            return Err(format!("Enumeration is limited to k <= {}, got {}", MAX_ENUMERATED_DIMENSION, k));
+        }
+        let min = (1u64..1 << k)
+            .map(|bits| {
+                let message = Vector::from((0..k).map(|i| Gf2::new(bits >> i & 1)).collect::<Vec<Gf2>>());
+                weight(&self.encode(&message))
+            })
+            .min();
+        Ok(min.unwrap_or(0))
+    }
+
+    fn build_coset_leaders(&self) -> Vec<Vector<Gf2>> {
+        let (n, r) = (self.len(), self.parity_check.rows);
+        if r > MAX_SYNDROME_BITS {
+            return Vec::new();
+        }
+        let mut leaders: Vec<Option<Vector<Gf2>>> = vec![None; 1 << r];
+        let mut found = 0;
+        let mut positions: Vec<usize> = Vec::new();
+
+        // Error patterns in order of weight, and lexicographically within a weight.
+        for w in 0..=n {
+            positions.clear();
+            positions.extend(0..w);
+            loop {
+                let mut pattern = Vector::from(vec![Gf2::zero(); n]);
+                for &p in &positions {
+                    pattern.data[p] = Gf2::one();
+                }
+                let slot = &mut leaders[syndrome_index(&self.syndrome(&pattern))];
+                if slot.is_none() {
+                    *slot = Some(pattern);
+                    found += 1;
+                    if found == leaders.len() {
+                        return leaders.into_iter().flatten().collect();
+                    }
+                }
+                if !next_combination(&mut positions, n) {
+                    break;
+                }
+            }
+        }
+        leaders.into_iter().map(|l| l.unwrap_or_else(|| Vector::from(vec![Gf2::zero(); n]))).collect()
+    }
+}
+
+pub fn weight(v: &Vector<Gf2>) -> usize {
+    v.data.iter().filter(|&&x| x != Gf2::zero()).count()
+}
+
+// rref together with its pivot columns.
+fn reduced(m: &Matrix<Gf2>) -> (Matrix<Gf2>, Vec<usize>) {
+    let rref = m.clone().rref();
+    let pivots = rref.data.iter().filter_map(|row| row.iter().position(|&x| x != Gf2::zero())).collect();
+    (rref, pivots)
+}
+
+// Basis of the vectors orthogonal to the rows of an rref matrix: one row per
+// free column q, with a one at q and -R[i][q] at the i-th pivot column.
+fn complement(rref: &Matrix<Gf2>, pivots: &[usize]) -> Matrix<Gf2> {
+    let free: Vec<usize> = (0..rref.cols).filter(|c| !pivots.contains(c)).collect();
+    let rows: Vec<Vec<Gf2>> = free.iter()
+        .map(|&q| {
+            let mut row = vec![Gf2::zero(); rref.cols];
+            row[q] = Gf2::one();
+            for (i, &p) in pivots.iter().enumerate() {
+                row[p] = -rref.data[i][q];
+            }
+            row
+        })
+        .collect();
+    Matrix { data: rows, rows: free.len(), cols: rref.cols }
+}
+
+fn syndrome_index(s: &Vector<Gf2>) -> usize {
+    s.data.iter().enumerate().map(|(i, &b)| (b.value() as usize) << i).sum()
+}
+
+// Advances `positions` (strictly increasing, below n) to the next
+// combination of the same size; false once the last one has been seen.
+fn next_combination(positions: &mut [usize], n: usize) -> bool {
+    let w = positions.len();
+    for i in (0..w).rev() {
+        if positions[i] < n - w + i {
+            positions[i] += 1;
+            for j in i + 1..w {
+                positions[j] = positions[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+    false
+}
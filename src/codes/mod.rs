@@ -0,0 +1,5 @@
+pub mod linear;
+pub mod hamming;
+
+pub use linear::LinearCode;
+pub use linear::weight;
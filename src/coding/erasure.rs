@@ -0,0 +1,87 @@
+use crate::types::Semiring;
+use crate::{Matrix, Gf256};
+
+/// Systematic erasure code over GF(2^8) with k data shards and m parity
+/// shards: any k of the k + m shards rebuild all of them. Row i of `matrix`
+/// gives shard i as a combination of the data shards, so its top k rows are
+/// the identity and every k rows of it are invertible.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErasureCode<const POLY: u16 = 0x11d> {
+    pub data_shards: usize,
+    pub parity_shards: usize,
+    pub matrix: Matrix<Gf256<POLY>>,
+}
+
+impl<const POLY: u16> ErasureCode<POLY> {
+    /// Vandermonde rows (1, x, x^2, ...) at x = 0, 1, ..., k + m - 1, times the
+    /// inverse of their top k x k block. Any k rows of a Vandermonde matrix
+    /// with distinct points are independent, and the change of basis keeps it so.
+    pub fn vandermonde(data_shards: usize, parity_shards: usize) -> Result<Self, String> {
+        let total = check_sizes(data_shards, parity_shards)?;
+        let v = Matrix::from((0..total).map(|x| {
+            (0..data_shards).map(|j| Gf256(x as u8).pow(j)).collect::<Vec<Gf256<POLY>>>()
+        }).collect::<Vec<_>>());
+        let top_inverse = v.submatrix(0, 0, data_shards, data_shards).inverse()?;
+        Ok(Self { data_shards, parity_shards, matrix: v.mul_mat(top_inverse) })
+    }
+
+    /// The identity over the Cauchy matrix 1 / (x_i + y_j) with x_i = k + i and
+    /// y_j = j. Every square submatrix of a Cauchy matrix is invertible.
+    pub fn cauchy(data_shards: usize, parity_shards: usize) -> Result<Self, String> {
+        check_sizes(data_shards, parity_shards)?;
+        let parity = Matrix::from((0..parity_shards).map(|i| {
+            (0..data_shards).map(|j| Gf256::one() / (Gf256((data_shards + i) as u8) + Gf256(j as u8))).collect::<Vec<Gf256<POLY>>>()
+        }).collect::<Vec<_>>());
+        let matrix = Matrix::vstack(&[&Matrix::identity(data_shards), &parity]);
+        Ok(Self { data_shards, parity_shards, matrix })
+    }
+
+    /// The m parity shards for k equally long data shards.
+    pub fn encode(&self, data: &[Vec<u8>]) -> Vec<Vec<u8>> {
+        assert_eq!(data.len(), self.data_shards, "Expected {} data shards, got {}", self.data_shards, data.len());
+        let rows = self.matrix.submatrix(self.data_shards, 0, self.parity_shards, self.data_shards);
+        to_shards(&rows.mul_mat(to_matrix(data)))
+    }
+
+    /// Fills in every `None` shard from any k shards that are present.
+    pub fn reconstruct(&self, shards: &mut [Option<Vec<u8>>]) -> Result<(), String> {
+        let (k, total) = (self.data_shards, self.data_shards + self.parity_shards);
+        if shards.len() != total {
+            return Err(format!("Expected {} shards, got {}", total, shards.len()));
+        }
+        let present: Vec<usize> = (0..total).filter(|&i| shards[i].is_some()).take(k).collect();
+        if present.len() < k {
+            return Err(format!("Need at least {} shards to reconstruct, got {}", k, present.len()));
+        }
+
+        let available: Vec<Vec<u8>> = present.iter().map(|&i| shards[i].clone().unwrap_or_default()).collect();
+        let mut rows = Matrix::from(present.iter().map(|&i| self.matrix.data[i].clone()).collect::<Vec<_>>());
+        let data = to_shards(&rows.inverse()?.mul_mat(to_matrix(&available)));
+        let encoded = to_shards(&self.matrix.mul_mat(to_matrix(&data)));
+
+        for (i, shard) in shards.iter_mut().enumerate() {
+            if shard.is_none() {
+                *shard = Some(encoded[i].clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_sizes(data_shards: usize, parity_shards: usize) -> Result<usize, String> {
+    let total = data_shards + parity_shards;
+    if data_shards == 0 || total > 256 {
+        return Err(format!("Need 0 < k and k + m <= 256, got k = {}, m = {}", data_shards, parity_shards));
+    }
+    Ok(total)
+}
+
+fn to_matrix<const POLY: u16>(shards: &[Vec<u8>]) -> Matrix<Gf256<POLY>> {
+    let len = shards.first().map_or(0, |s| s.len());
+    assert!(shards.iter().all(|s| s.len() == len), "All shards must have the same length");
+    Matrix::from(shards.iter().map(|s| s.iter().map(|&b| Gf256(b)).collect::<Vec<_>>()).collect::<Vec<_>>())
+}
+
+fn to_shards<const POLY: u16>(m: &Matrix<Gf256<POLY>>) -> Vec<Vec<u8>> {
+    m.data.iter().map(|row| row.iter().map(|b| b.0).collect()).collect()
+}
@@ -0,0 +1,5 @@
+pub mod reed_solomon;
+pub mod erasure;
+
+pub use reed_solomon::ReedSolomon;
+pub use erasure::ErasureCode;
@@ -0,0 +1,132 @@
+use crate::types::Semiring;
+use crate::{Matrix, Vector, Gf256};
+
+/// Systematic Reed-Solomon code RS(n, k) over GF(2^8): a codeword is the k
+/// message bytes followed by n - k parity bytes, and up to (n - k) / 2 wrong
+/// bytes anywhere in it can be corrected. The generator polynomial has the
+/// roots α^0, ..., α^(n-k-1).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReedSolomon<const POLY: u16 = 0x11d> {
+    pub n: usize,
+    pub k: usize,
+    // Monic, highest degree first.
+    generator: Vec<Gf256<POLY>>,
+}
+
+impl<const POLY: u16> ReedSolomon<POLY> {
+    pub fn new(n: usize, k: usize) -> Result<Self, String> {
+        if k == 0 || k >= n || n > 255 {
+            return Err(format!("RS({}, {}) needs 0 < k < n <= 255", n, k));
+        }
+        let mut generator = vec![Gf256::one()];
+        for i in 0..n - k {
+            let root = Gf256::exp(i);
+            let mut next = vec![Gf256::zero(); generator.len() + 1];
+            for (j, &g) in generator.iter().enumerate() {
+                next[j] += g;
+                next[j + 1] += g * root;
+            }
+            generator = next;
+        }
+        Ok(Self { n, k, generator })
+    }
+
+    /// Number of byte errors a codeword can carry and still be corrected.
+    pub fn capacity(&self) -> usize {
+        (self.n - self.k) / 2
+    }
+
+    /// Message followed by the remainder of m(x) x^(n-k) divided by g(x).
+    #[allow(clippy::needless_range_loop)]
+    pub fn encode(&self, message: &[u8]) -> Vec<u8> {
+        assert_eq!(message.len(), self.k, "Message must have {} bytes, got {}", self.k, message.len());
+        let parity = self.n - self.k;
+        let mut remainder = vec![Gf256::<POLY>::zero(); parity];
+        for &byte in message {
+            let feedback = Gf256(byte) + remainder[0];
+            remainder.rotate_left(1);
+            remainder[parity - 1] = Gf256::zero();
+            for j in 0..parity {
+                remainder[j] += feedback * self.generator[j + 1];
+            }
+        }
+
+        let mut codeword = message.to_vec();
+        codeword.extend(remainder.iter().map(|r| r.0));
+        codeword
+    }
+
+    /// S_j = c(α^j) for j < n - k; all zero exactly when `codeword` is valid.
+    pub fn syndromes(&self, codeword: &[u8]) -> Vec<Gf256<POLY>> {
+        assert_eq!(codeword.len(), self.n, "Codeword must have {} bytes, got {}", self.n, codeword.len());
+        (0..self.n - self.k)
+            .map(|j| {
+                let x = Gf256::exp(j);
+                codeword.iter().fold(Gf256::zero(), |acc, &c| acc * x + Gf256(c))
+            })
+            .collect()
+    }
+
+    /// Corrected message bytes.
+    pub fn decode(&self, received: &[u8]) -> Result<Vec<u8>, String> {
+        let mut codeword = received.to_vec();
+        self.correct(&mut codeword)?;
+        codeword.truncate(self.k);
+        Ok(codeword)
+    }
+
+    /// Peterson-Gorenstein-Zierler decoding in place; returns how many bytes
+    /// were fixed. The error locator comes from the largest nonsingular
+    /// syndrome system and the error values from a Vandermonde system, both
+    /// solved with `Matrix::inverse`.
+    pub fn correct(&self, codeword: &mut [u8]) -> Result<usize, String> {
+        let s = self.syndromes(codeword);
+        if s.iter().all(|&v| v == Gf256::zero()) {
+            return Ok(0);
+        }
+
+        for nu in (1..=self.capacity()).rev() {
+            // Newton's identities: sum_i λ_i S_(j+ν-i) = S_(j+ν) for j < ν.
+            let system = Matrix::from((0..nu).map(|j| (0..nu).map(|i| s[j + nu - 1 - i]).collect::<Vec<_>>()).collect::<Vec<_>>());
+            let inverse = match system.clone().inverse() {
+                Ok(inverse) => inverse,
+                Err(_) => continue,
+            };
+            let lambda = inverse.mul_vec(Vector::from((0..nu).map(|j| s[j + nu]).collect::<Vec<_>>()));
+
+            // Chien search: position p has locator X = α^(n-1-p), and is in
+            // error when Λ(X^-1) = 1 + λ_1 X^-1 + ... + λ_ν X^-ν vanishes.
+            let positions: Vec<usize> = (0..self.n)
+                .filter(|&p| {
+                    let x_inv = Gf256::exp(255 - (self.n - 1 - p));
+                    let mut power = Gf256::one();
+                    let mut value = Gf256::one();
+                    for &l in &lambda.data {
+                        power *= x_inv;
+                        value += l * power;
+                    }
+                    value == Gf256::zero()
+                })
+                .collect();
+            if positions.len() != nu {
+                break;
+            }
+
+            // S_j = sum_i e_i X_i^j for j < ν.
+            let locators: Vec<Gf256<POLY>> = positions.iter().map(|&p| Gf256::exp(self.n - 1 - p)).collect();
+            let vandermonde = Matrix::from((0..nu).map(|j| locators.iter().map(|x| x.pow(j)).collect::<Vec<_>>()).collect::<Vec<_>>());
+            let magnitudes = vandermonde.clone().inverse()?.mul_vec(Vector::from(s[..nu].to_vec()));
+
+            let mut fixed = codeword.to_vec();
+            for (&p, e) in positions.iter().zip(&magnitudes.data) {
+                fixed[p] ^= e.0;
+            }
+            if self.syndromes(&fixed).iter().any(|&v| v != Gf256::zero()) {
+                break;
+            }
+            codeword.copy_from_slice(&fixed);
+            return Ok(nu);
+        }
+        Err(format!("Too many errors to correct (at most {})", self.capacity()))
+    }
+}
@@ -0,0 +1,61 @@
+use crate::types::Semiring;
+use crate::{Matrix, MinPlus, MaxPlus, Boolean};
+
+/// Directed graph on nodes 0..nodes as a weighted edge list. Parallel edges
+/// are allowed; the matrix builders keep whichever one the semiring prefers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Graph {
+    pub nodes: usize,
+    pub edges: Vec<(usize, usize, f32)>,
+}
+
+impl Graph {
+    pub fn new(nodes: usize) -> Self {
+        Self { nodes, edges: Vec::new() }
+    }
+
+    pub fn from_edges(nodes: usize, edges: &[(usize, usize, f32)]) -> Self {
+        let mut g = Self::new(nodes);
+        for &(from, to, weight) in edges {
+            g.add_edge(from, to, weight);
+        }
+        g
+    }
+
+    /// Unweighted edges, each with weight 1.
+    pub fn from_pairs(nodes: usize, pairs: &[(usize, usize)]) -> Self {
+        let mut g = Self::new(nodes);
+        for &(from, to) in pairs {
+            g.add_edge(from, to, 1.0);
+        }
+        g
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: f32) {
+        assert!(from < self.nodes && to < self.nodes,
+            "Edge ({}, {}) out of range for {} nodes", from, to, self.nodes);
+        self.edges.push((from, to, weight));
+    }
+
+    /// Entry (i, j) is the lightest i -> j edge weight, +inf without an edge.
+    pub fn min_plus_matrix(&self) -> Matrix<MinPlus<f32>> {
+        self.matrix(MinPlus)
+    }
+
+    /// Entry (i, j) is the heaviest i -> j edge weight, -inf without an edge.
+    pub fn max_plus_matrix(&self) -> Matrix<MaxPlus<f32>> {
+        self.matrix(MaxPlus)
+    }
+
+    pub fn adjacency(&self) -> Matrix<Boolean> {
+        self.matrix(|_| Boolean(true))
+    }
+
+    fn matrix<K: Semiring>(&self, entry: impl Fn(f32) -> K) -> Matrix<K> {
+        let mut m = Matrix::from(vec![vec![K::zero(); self.nodes]; self.nodes]);
+        for &(from, to, weight) in &self.edges {
+            m.data[from][to] += entry(weight);
+        }
+        m
+    }
+}
@@ -0,0 +1,9 @@
+pub mod digraph;
+pub mod paths;
+
+pub use digraph::Graph;
+pub use paths::Paths;
+pub use paths::shortest_paths;
+pub use paths::longest_paths;
+pub use paths::reachability;
+pub use paths::transitive_closure;
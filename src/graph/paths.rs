@@ -0,0 +1,121 @@
+use crate::types::Semiring;
+use crate::{Matrix, MinPlus, MaxPlus, Boolean};
+use super::digraph::Graph;
+
+/// All-pairs best path weights, plus next-hop pointers for rebuilding the
+/// paths themselves. `K` must be idempotent (a + a == a), as min-plus,
+/// max-plus and Boolean are, so that `+` picks the better of two paths.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Paths<K: Semiring> {
+    pub weights: Matrix<K>,
+    next: Vec<Vec<Option<usize>>>,
+}
+
+impl<K: Semiring> Paths<K> {
+    /// A* = I + A + A^2 + ... by repeated squaring of I + A, tracking for every
+    /// pair the first hop of the path that won. Squaring stops once walks of n
+    /// edges are covered, enough for every simple path and every simple cycle.
+    pub fn closure(a: &Matrix<K>) -> Self {
+        assert!(a.is_square(), "Path closure needs a square matrix, got {:?}", a.shape());
+        let n = a.rows;
+        let mut weights = Matrix::identity(n);
+        weights.add(a.clone());
+        let mut next: Vec<Vec<Option<usize>>> = (0..n)
+            .map(|i| (0..n).map(|j| if i == j || a.data[i][j] != K::zero() { Some(j) } else { None }).collect())
+            .collect();
+
+        let mut covered = 1;
+        while covered < n {
+            (weights, next) = square(&weights, &next);
+            covered *= 2;
+        }
+        Paths { weights, next }
+    }
+
+    pub fn weight(&self, from: usize, to: usize) -> K {
+        self.weights.data[from][to].clone()
+    }
+
+    pub fn is_reachable(&self, from: usize, to: usize) -> bool {
+        self.weights.data[from][to] != K::zero()
+    }
+
+    /// Nodes of the best path from `from` to `to`, both ends included, or
+    /// `None` when `to` cannot be reached.
+    pub fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        if !self.is_reachable(from, to) {
+            return None;
+        }
+        let mut path = vec![from];
+        let mut node = from;
+        while node != to {
+            node = self.next[node][to]?;
+            path.push(node);
+            if path.len() > self.weights.rows {
+                return None;
+            }
+        }
+        Some(path)
+    }
+
+    /// Nodes lying on a cycle that beats the empty path: negative cycles under
+    /// min-plus, positive ones under max-plus. Paths through them are unbounded.
+    pub fn improving_cycle_nodes(&self) -> Vec<usize> {
+        (0..self.weights.rows).filter(|&i| improves(self.weights.data[i][i].clone(), K::one())).collect()
+    }
+}
+
+/// All-pairs shortest paths by min-plus closure. Fails on a negative cycle.
+pub fn shortest_paths(g: &Graph) -> Result<Paths<MinPlus<f32>>, String> {
+    let paths = Paths::closure(&g.min_plus_matrix());
+    match paths.improving_cycle_nodes().first() {
+        Some(node) => Err(format!("Negative cycle through node {}", node)),
+        None => Ok(paths),
+    }
+}
+
+/// All-pairs longest paths by max-plus closure. Only defined for a DAG.
+pub fn longest_paths(g: &Graph) -> Result<Paths<MaxPlus<f32>>, String> {
+    let closure = transitive_closure(g);
+    if let Some(node) = (0..g.nodes).find(|&i| closure.data[i][i].0) {
+        return Err(format!("Graph has a cycle through node {}", node));
+    }
+    Ok(Paths::closure(&g.max_plus_matrix()))
+}
+
+/// Reflexive reachability: entry (i, j) is true when j can be reached from i
+/// in zero or more steps.
+pub fn reachability(g: &Graph) -> Paths<Boolean> {
+    Paths::closure(&g.adjacency())
+}
+
+/// A+ = A A*: entry (i, j) is true when j can be reached from i in one or
+/// more steps, so the diagonal marks the nodes on a cycle.
+pub fn transitive_closure(g: &Graph) -> Matrix<Boolean> {
+    g.adjacency().mul_mat(reachability(g).weights)
+}
+
+// In an idempotent semiring `candidate` is strictly better when adding it to
+// `current` gives `candidate` back.
+fn improves<K: Semiring>(candidate: K, current: K) -> bool {
+    candidate != current && candidate.clone() + current == candidate
+}
+
+#[allow(clippy::needless_range_loop)]
+fn square<K: Semiring>(w: &Matrix<K>, next: &[Vec<Option<usize>>]) -> (Matrix<K>, Vec<Vec<Option<usize>>>) {
+    let n = w.rows;
+    let mut weights = w.clone();
+    let mut hops = next.to_vec();
+    for i in 0..n {
+        for j in 0..n {
+            for k in 0..n {
+                let candidate = w.data[i][k].clone() * w.data[k][j].clone();
+                if improves(candidate.clone(), weights.data[i][j].clone()) {
+                    weights.data[i][j] = candidate;
+                    hops[i][j] = next[i][k];
+                }
+            }
+        }
+    }
+    (weights, hops)
+}
@@ -1,4 +1,10 @@
 pub mod types;
+pub mod solvers;
+pub mod sparse;
+pub mod structured;
+pub mod graph;
+pub mod coding;
+pub mod codes;
 
 pub use types::Vector;
 pub use types::Matrix;
@@ -7,4 +13,40 @@ pub use types::angle_cos;
 pub use types::cross_product;
 pub use types::projection;
 pub use types::lerp;
-pub use types::Complex;
\ No newline at end of file
+pub use types::Complex;
+pub use types::Quaternion;
+pub use types::Dual;
+pub use types::gradient;
+pub use types::jacobian;
+pub use types::Interval;
+pub use types::verify_solution;
+pub use types::Fixed;
+pub use types::FixedError;
+pub use types::projection_fixed;
+pub use types::F16;
+pub use types::BF16;
+pub use types::Expr;
+pub use types::Polynomial;
+pub use types::roots;
+pub use types::Real;
+pub use types::BlockMatrix;
+pub use types::RowOp;
+pub use types::RowOpLog;
+pub use types::EliminationTrace;
+pub use types::TraceFormat;
+pub use types::Rational;
+pub use types::BigInt;
+pub use types::BigRational;
+pub use types::MinPlus;
+pub use types::MaxPlus;
+pub use types::Boolean;
+pub use types::Fp;
+pub use types::Gf2;
+pub use types::Gf256;
+
+pub use solvers::LinearOperator;
+pub use solvers::Preconditioner;
+pub use solvers::SolverOptions;
+pub use solvers::ConvergenceReport;
+
+pub use sparse::SparseMatrix;
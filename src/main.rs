@@ -1,4 +1,10 @@
-use matrix::{Vector, Matrix, Complex,linear_combination, lerp, angle_cos, cross_product, projection};
+use matrix::{Vector, Matrix, Complex, Quaternion, Dual, gradient, jacobian, Interval, verify_solution, Polynomial, roots, Fixed, projection_fixed, F16, BF16, Expr,linear_combination, lerp, angle_cos, cross_product, projection, SolverOptions, SparseMatrix, BlockMatrix, RowOpLog, TraceFormat, Rational, BigInt, BigRational, Fp, Gf2};
+use matrix::solvers::{conjugate_gradient, bicgstab, gmres, IncompleteCholesky};
+use matrix::sparse::{FillOrdering, SymbolicCholesky, SparseLu};
+use matrix::structured::{Tridiagonal, Banded, UpperTriangular};
+use matrix::graph::{Graph, shortest_paths, longest_paths, transitive_closure};
+use matrix::coding::{ReedSolomon, ErasureCode};
+use matrix::codes::LinearCode;
 
 fn main(){
     let mut u = Vector::from([2.0, 3.0]);
@@ -395,5 +401,430 @@ fn main(){
     ]);
     println!("transpose {}", v.transpose());
 
+    let a = Matrix::from([
+    [4., -1., 0.],
+    [-1., 4., -1.],
+    [0., -1., 4.],
+    ]);
+    let b = Vector::from([2., 4., 10.]);
+    let opts = SolverOptions::new().tol(1e-6);
+    let report = conjugate_gradient(&a, &b, &opts);
+    println!("cg: converged={} iterations={}\n{}", report.converged, report.iterations, report.x);
+    // [1.]
+    // [2.]
+    // [3.]
+    let report = bicgstab(&a, &b, &opts);
+    println!("bicgstab: converged={} iterations={}\n{}", report.converged, report.iterations, report.x);
+    let report = gmres(&a, &b, &SolverOptions::new().restart(2));
+    println!("gmres: converged={} residuals={:?}\n{}", report.converged, report.residual_history, report.x);
+    let report = a.jacobi(&b, &opts);
+    println!("jacobi: converged={} iterations={}", report.converged, report.iterations);
+    let report = a.gauss_seidel(&b, &opts);
+    println!("gauss-seidel: converged={} iterations={}", report.converged, report.iterations);
+    let report = a.sor(&b, 1.1, &opts);
+    println!("sor: converged={} iterations={}\n{}", report.converged, report.iterations, report.x);
+    // [1.]
+    // [2.]
+    // [3.]
+    let ic = IncompleteCholesky::new(&a).unwrap();
+    let report = conjugate_gradient(&a, &b, &SolverOptions::new().preconditioner(&ic));
+    println!("cg + ic(0): converged={} iterations={}", report.converged, report.iterations);
+    // converged=true iterations=1
+    let mut s = SparseMatrix::new(3, 3);
+    s.push(0, 0, 4.);
+    s.push(0, 1, -1.);
+    s.push(1, 0, -1.);
+    s.push(1, 1, 4.);
+    s.push(1, 2, -1.);
+    s.push(2, 1, -1.);
+    s.push(2, 2, 2.);
+    s.push(2, 2, 2.);
+    let s = s.to_csr();
+    println!("nnz = {}\n{}", s.nnz(), s.to_dense());
+    // nnz = 7
+    // [4.0, -1.0, 0.0]
+    // [-1.0, 4.0, -1.0]
+    // [0.0, -1.0, 4.0]
+    println!("{}", s.mul_vec(Vector::from([1., 2., 3.])));
+    // [2.]
+    // [4.]
+    // [10.]
+    println!("{}", s.mul_sparse(&s.transpose()).to_dense());
+    let report = conjugate_gradient(&s, &b, &opts);
+    println!("sparse cg: converged={}\n{}", report.converged, report.x);
+    let symbolic = SymbolicCholesky::analyze(&s, FillOrdering::MinimumDegree).unwrap();
+    let mut chol = symbolic.factor(&s).unwrap();
+    println!("{}", chol.solve(&b));
+    // [1.]
+    // [2.]
+    // [3.]
+    let mut s2 = s.clone();
+    s2.scl(2.);
+    chol.refactor(&s2).unwrap();
+    println!("{}", chol.solve(&b));
+    // [0.5]
+    // [1.]
+    // [1.5]
+    let lu = SparseLu::new(&s, FillOrdering::ReverseCuthillMcKee).unwrap();
+    println!("{}", lu.solve(&b));
+    // [1.]
+    // [2.]
+    // [3.]
+    let t = Tridiagonal::from([-1., -1.], [4., 4., 4.], [-1., -1.]);
+    println!("{}", t.solve(&b).unwrap());
+    // [1.]
+    // [2.]
+    // [3.]
+    let band = Banded::from_dense(&a, 1, 1);
+    println!("{}", band.solve(&b).unwrap());
+    // [1.]
+    // [2.]
+    // [3.]
+    let u = UpperTriangular::from_dense(&Matrix::from([
+    [2., 1., 1.],
+    [0., 1., 2.],
+    [0., 0., 4.],
+    ]));
+    println!("{}", u.solve(&Vector::from([7., 8., 12.])).unwrap());
+    // [1.]
+    // [2.]
+    // [3.]
+    let i2 = Matrix::from([
+    [1., 0.],
+    [0., 1.],
+    ]);
+    let k = Matrix::from([
+    [0., 1.],
+    [1., 0.],
+    ]);
+    println!("{}", i2.kronecker(&k));
+    // [0.0, 1.0, 0.0, 0.0]
+    // [1.0, 0.0, 0.0, 0.0]
+    // [0.0, 0.0, 0.0, 1.0]
+    // [0.0, 0.0, 1.0, 0.0]
+    println!("{}", Vector::from([1., 2.]).outer(&Vector::from([3., 4.])));
+    // [3.0, 4.0]
+    // [6.0, 8.0]
+    let m = Matrix::block(&[&[&a, &b.outer(&b)], &[&Matrix::identity(3), &a]]);
+    let blocks = BlockMatrix::split(&m, &[3, 3], &[3, 3]);
+    let lu = blocks.lu().unwrap();
+    println!("det = {} vs {}", lu.determinant(), m.clone().determinant());
+    let a = Matrix::from([
+    [1., 2.],
+    [3., 4.],
+    ]);
+    let mut log = RowOpLog::new();
+    println!("{}", a.clone().row_echelon_with_log(&mut log));
+    print!("{}", log);
+    // 1. R1 ↔ R2
+    // 2. R1 ← R1 / 3
+    // 3. R2 ← R2 − 1·R1
+    // 4. R2 ← R2 / 0.6666666
+    println!("{}", log.replay(&a));
+    for e in log.elementary_matrices(2) {
+        println!("{}", e);
+    }
+    let u = Matrix::from([
+    [8., 5., -2.],
+    [4., 7., 20.],
+    [7., 6., 1.],
+    ]);
+    println!("{}", u.explain_determinant());
+    // R2 ← R2 − 0.5·R1
+    // R3 ← R3 − 0.875·R1
+    // R3 ← R3 − 0.3611111·R2
+    // det = (-1)^0 · 8 · 4.5 · -4.833333 = -173.99998
+    println!("{}", u.explain_rank().render(TraceFormat::Markdown));
+    println!("{}", a.explain_inverse().render(TraceFormat::Latex));
 
-}
\ No newline at end of file
+    let q = u.to_rational();
+    println!("{}", q.clone().determinant());
+    // -174
+    println!("{}", q.clone().inverse().unwrap());
+    // [113/174, 17/174, -19/29]
+    // [-68/87, -11/87, 28/29]
+    // [25/174, 13/174, -6/29]
+    println!("{}", Matrix::from([
+    [8., 5., -2., 4., 28.],
+    [4., 2.5, 20., 4., -4.],
+    [8., 5., 1., 4., 17.],
+    ]).to_rational().rref());
+    // [1, 5/8, 0, 0, -73/6]
+    // [0, 0, 1, 0, -11/3]
+    // [0, 0, 0, 1, 59/2]
+    println!("{} {}", Rational::from(0.1), Rational::new(6, -8));
+    // 1/10 -3/4
+    let vandermonde = Matrix::from((1..=20).map(|x| {
+        (0..20).map(|p| BigInt::from(x).pow(p)).collect::<Vec<BigInt>>()
+    }).collect::<Vec<Vec<BigInt>>>());
+    println!("{}", vandermonde.determinant());
+    // 1!·2!·...·19! = 523827226948912906162136183269887782788685420217963126789982275317725639664591791615428617583779071590924288000000000000000000000000000000
+    let hilbert = Matrix::from((1..=6).map(|i| {
+        (1..=6).map(|j| BigRational::new(BigInt::from(1), BigInt::from(i + j - 1))).collect::<Vec<BigRational>>()
+    }).collect::<Vec<Vec<BigRational>>>());
+    println!("{}", hilbert.clone().determinant());
+    // 1/186313420339200000
+    let wide = Matrix::from((0..50u64).map(|i| {
+        (0..50u64).map(|j| BigInt::from(((i * 50 + j + 1).wrapping_mul(0x9E3779B97F4A7C15) >> 16) as i64)).collect::<Vec<BigInt>>()
+    }).collect::<Vec<Vec<BigInt>>>());
+    let det = wide.determinant();
+    println!("{} {} bits", det.signum(), det.bits());
+    // -1 1907 bits
+    println!("{}", "-123456789012345678901234567890".parse::<BigInt>().unwrap() * BigInt::from(10).pow(10));
+    // -1234567890123456789012345678900000000000
+    let ints = Matrix::from([
+    [1i64, 2],
+    [3, 4],
+    ]);
+    println!("{}{}", ints.mul_mat(ints.transpose()), ints.trace());
+    // [5, 11]
+    // [11, 25]
+    // 5
+    let step = Matrix::from([
+    [1u64, 1],
+    [1, 0],
+    ]);
+    let mut fib = Matrix::identity(2);
+    for _ in 0..50 {
+        fib = fib.mul_mat(step.clone());
+    }
+    println!("{}", fib.get(0, 1));
+    // 12586269025
+    let roads = Graph::from_edges(4, &[(0, 1, 4.0), (0, 2, 1.0), (2, 1, 2.0), (1, 3, 1.0), (2, 3, 5.0)]);
+    let mut hops = Matrix::identity(4);
+    hops.add(roads.min_plus_matrix());
+    println!("{}", hops.pow(3));
+    let shortest = shortest_paths(&roads).unwrap();
+    println!("{} via {:?}", shortest.weight(0, 3), shortest.path(0, 3));
+    // 4 via Some([0, 2, 1, 3])
+    let longest = longest_paths(&roads).unwrap();
+    println!("{} via {:?}", longest.weight(0, 3), longest.path(0, 3));
+    // 6 via Some([0, 2, 3])
+    println!("{}", transitive_closure(&Graph::from_pairs(3, &[(0, 1), (1, 0), (1, 2)])));
+    // [1, 1, 1]
+    // [1, 1, 1]
+    // [0, 0, 0]
+    let gf7 = Matrix::from([[1u64, 2, 3], [4, 5, 6], [7, 8, 9]].map(|row| row.map(Fp::<7>::new)));
+    println!("{}\n{}", gf7.clone().rank(), gf7.clone().row_echelon());
+    // 2
+    // [1, 3, 5]
+    // [0, 1, 2]
+    // [0, 0, 0]
+    let gf5 = Matrix::from([[1u64, 2], [3, 4]].map(|row| row.map(Fp::<5>::new)));
+    let gf5_inv = gf5.clone().inverse().unwrap();
+    println!("{}{}", gf5_inv, gf5.mul_mat_lazy(&gf5_inv));
+    // [3, 1]
+    // [4, 2]
+    // [1, 0]
+    // [0, 1]
+    let rs: ReedSolomon = ReedSolomon::new(12, 6).unwrap();
+    let mut sent = rs.encode(b"matrix");
+    sent[1] ^= 0x20;
+    sent[9] = 0;
+    sent[11] ^= 0xff;
+    println!("{:?}", rs.decode(&sent).map(|m| String::from_utf8_lossy(&m).into_owned()));
+    // Ok("matrix")
+    let code: ErasureCode = ErasureCode::cauchy(3, 2).unwrap();
+    let data = vec![b"ab".to_vec(), b"cd".to_vec(), b"ef".to_vec()];
+    let parity = code.encode(&data);
+    let mut shards = vec![None, Some(data[1].clone()), None, Some(parity[0].clone()), Some(parity[1].clone())];
+    code.reconstruct(&mut shards).unwrap();
+    println!("{:?}", shards.iter().take(3).map(|s| String::from_utf8_lossy(s.as_ref().unwrap()).into_owned()).collect::<Vec<String>>());
+    // ["ab", "cd", "ef"]
+    let hamming = LinearCode::hamming_7_4();
+    println!("{}", hamming.systematic_generator());
+    // [1, 0, 0, 0, 0, 1, 1]
+    // [0, 1, 0, 0, 1, 0, 1]
+    // [0, 0, 1, 0, 1, 1, 0]
+    // [0, 0, 0, 1, 1, 1, 1]
+    let message = Vector::from([1u64, 0, 1, 1].map(Gf2::new));
+    let mut received = hamming.encode(&message);
+    received.data[4] += Gf2::new(1);
+    println!("{}", hamming.syndrome(&received));
+    // [1]
+    // [0]
+    // [1]
+    println!("{}", hamming.decode(&received).unwrap());
+    // [1]
+    // [0]
+    // [1]
+    // [1]
+    println!("{:?} {:?}", hamming.minimum_distance(), LinearCode::extended_hamming(3).minimum_distance());
+    // Ok(3) Ok(4)
+    let z: Complex<f64> = "3-4i".parse().unwrap();
+    println!("{} {} {}", z.conj(), z.modulus(), z.sqrt());
+    // 3+4i 5 2-1i
+    let (r, theta) = Complex::from(-1.0f64, 0.0).to_polar();
+    println!("{} {}", r, theta);
+    // 1 3.141592653589793
+    println!("{}", Complex::<f64>::i().powc(Complex::i()));
+    // 0.20787957635076193+0i
+    println!("{}", Complex::from(0.0f64, 1.0).sinh());
+    // 0+0.8414709848078965i
+    let z = Matrix::from([
+        [Complex::from(1.0f32, 2.0), Complex::from(0.0, -1.0)],
+        [Complex::from(3.0, 0.0), Complex::from(4.0, 5.0)],
+    ]);
+    println!("{}", z.conjugate_transpose());
+    // [1-2i, 3-0i]
+    // [0+1i, 4-5i]
+    // Complex division edge cases: Baudin and Smith's hard quotients, then
+    // the C99 Annex G rules for zeros, infinities and NaN.
+    let big = |e: i32| 2f64.powi(e);
+    let (inf, nan) = (f64::INFINITY, f64::NAN);
+    let quotients = [
+        (Complex::from(1.0, 1.0), Complex::from(1.0, big(1023))),
+        (Complex::from(1.0, 1.0), Complex::from(big(-1023), big(-1023))),
+        (Complex::from(big(1023), big(-1023)), Complex::from(big(677), big(-677))),
+        (Complex::from(big(1023), big(1023)), Complex::from(1.0, 1.0)),
+        (Complex::from(big(1015), big(-989)), Complex::from(big(1023), big(1023))),
+        (Complex::from(1e300, 1e300), Complex::from(1e300, 1e300)),
+        (Complex::from(1.0, 1.0), Complex::from(0.0, 0.0)),
+        (Complex::from(1.0, 2.0), Complex::from(-0.0, 0.0)),
+        (Complex::from(inf, nan), Complex::from(1.0, 1.0)),
+        (Complex::from(1.0, 1.0), Complex::from(inf, nan)),
+        (Complex::from(0.0, 0.0), Complex::from(0.0, 0.0)),
+        (Complex::from(inf, 0.0), Complex::from(inf, 0.0)),
+    ];
+    for (x, y) in quotients {
+        let q = x / y;
+        println!("{:e}{:+e}i", q.re, q.im);
+    }
+    // 1.1125369292536007e-308-1.1125369292536007e-308i
+    // 8.98846567431158e307+0e0i
+    // 1.4334366349937947e104-3.645561009778199e-304i
+    // 8.98846567431158e307+0e0i
+    // 1.953125e-3-1.953125e-3i
+    // 1e0+0e0i
+    // inf+infi
+    // -inf-infi
+    // inf-infi
+    // 0e0+0e0i
+    // NaNNaNi
+    // NaNNaNi
+    println!("{} {}", Complex::from(inf, nan) * Complex::from(1.0, 1.0), Complex::from(inf, inf) * Complex::from(1.0, 0.0));
+    // inf+infi inf+infi
+    println!("{:e} {:e} {}", Complex::from(3e200, 4e200).modulus(), Complex::from(3e-200, 4e-200).modulus(), Complex::from(nan, inf).modulus());
+    // 4.9999999999999995e200 5e-200 inf
+    let quarter = Quaternion::from_axis_angle(&Vector::from([0., 0., 1.]), std::f32::consts::FRAC_PI_2);
+    println!("{}", quarter);
+    // 0.70710677+0i+0j+0.70710677k
+    println!("{}", quarter.rotate(&Vector::from([1., 0., 0.])));
+    // [0.000000059604645]
+    // [0.99999994]
+    // [0]
+    println!("{}", Quaternion::slerp(Quaternion::identity(), quarter, 0.5));
+    // 0.9238796+0i+0j+0.3826835k
+    println!("{}", lerp(Quaternion::identity(), quarter, 0.5));
+    // 0.92387956+0i+0j+0.38268343k
+    let joint = Quaternion::from_euler(0.1, 0.2, 0.3);
+    println!("{:?}", Quaternion::from_rotation_matrix(&joint.to_homogeneous()).map(|q| q.to_euler()));
+    // Ok((0.099999994, 0.20000002, 0.3))
+    println!("{:?}", Quaternion::from_rotation_matrix(&Matrix::from([[2., 0., 0.], [0., 1., 0.], [0., 0., 1.]])));
+    // Err("Matrix is not a rotation (entries off by 1)")
+    // d/dt of det and inverse of [[t, 2], [1, t]] at t = 3.
+    let t = Dual::variable(3.0f64);
+    let mut m = Matrix::from([[t, Dual::constant(2.0)], [Dual::constant(1.0), t]]);
+    println!("{}", m.clone().determinant());
+    // 7+6ε
+    println!("{}", m.inverse().unwrap());
+    // [0.42857142857142855-0.22448979591836735ε, -0.2857142857142857+0.24489795918367344ε]
+    // [-0.14285714285714285+0.12244897959183672ε, 0.42857142857142855-0.22448979591836735ε]
+    println!("{}", Vector::from([t, Dual::constant(4.0)]).euclidean_norm());
+    // 5+0.6000000000000001ε
+    let f = |x: &Vector<Dual<f64>>| x.data[0] * x.data[1] + x.data[1].sin();
+    println!("{}", gradient(f, &Vector::from([2.0, 0.0])));
+    // [0]
+    // [3]
+    let polar = |x: &Vector<Dual<f32>>| Vector::from([x.data[0] * x.data[1].cos(), x.data[0] * x.data[1].sin()]);
+    println!("{}", jacobian(polar, &Vector::from([2.0, 0.0])));
+    // [1, 0]
+    // [0, 2]
+    println!("{} {}", Interval::point(0.1) + Interval::point(0.2), Interval::point(1.0) / Interval::point(3.0));
+    // [0.3, 0.30000000000000004] [0.3333333333333333, 0.33333333333333337]
+    let a = Matrix::from([[1., 2.], [3., 4.]]);
+    println!("{}", a.to_interval().determinant());
+    // [-2.0000000000000004, -1.9999999999999996]
+    println!("{}", a.to_interval().inverse().unwrap());
+    // [[-2.0000000000000013, -1.9999999999999984], [0.9999999999999992, 1.0000000000000007]]
+    // [[1.4999999999999998, 1.5000000000000004], [-0.5000000000000002, -0.4999999999999999]]
+    let b = Vector::from([5., 6.]);
+    let x = a.clone().inverse().unwrap().mul_vec(b.clone());
+    println!("{}", verify_solution(&a, &b, &x).unwrap());
+    // [[-4, -4]]
+    // [[4.5, 4.5]]
+    println!("{:?}", verify_solution(&Matrix::from([[1., 2.], [2., 4.]]), &b, &x));
+    // Err("Matrix is singular")
+    let jordan = Matrix::from([
+        [2., 1., 0., 0.],
+        [0., 2., 0., 0.],
+        [0., 0., 2., 0.],
+        [0., 0., 0., 3.],
+    ]).to_rational();
+    let chi = jordan.characteristic_polynomial();
+    let mu = jordan.minimal_polynomial();
+    println!("{} | {}", chi, mu);
+    // x^4 - 9x^3 + 30x^2 - 44x + 24 | x^3 - 7x^2 + 16x - 12
+    println!("{}", jordan.evaluate_polynomial(&chi));
+    // [0, 0, 0, 0]
+    // [0, 0, 0, 0]
+    // [0, 0, 0, 0]
+    // [0, 0, 0, 0]
+    let (quotient, remainder) = chi.div_rem(&mu);
+    println!("{} rem {}; gcd {}", quotient, remainder, chi.gcd(&chi.derivative()));
+    // x - 2 rem 0; gcd x^2 - 4x + 4
+    let p = Polynomial::new(vec![Rational::new(-3, 2), Rational::new(0, 1), Rational::new(1, 1)]);
+    println!("{} at 2 is {}", p, p.evaluate(Rational::new(2, 1)));
+    // x^2 - 3/2 at 2 is 5/2
+    let zeros = roots(&[24., -50., 35., -10., 1.]).unwrap();
+    println!("{}", zeros.iter().map(|z| z.to_string()).collect::<Vec<_>>().join(", "));
+    // 1+0i, 2+0i, 3+0i, 4+0i
+    let butterworth = Polynomial::new(vec![1., 2.6131259, 3.4142137, 2.6131259, 1.]);
+    println!("{}", butterworth.roots().unwrap().iter().map(|z| z.to_string()).collect::<Vec<_>>().join(", "));
+    // -0.9238794-0.3826838i, -0.9238794+0.3826838i, -0.38268352-0.9238795i, -0.38268352+0.9238795i
+    println!("{:?}", roots(&[0., 0.]));
+    // Err("The zero polynomial has no well-defined roots")
+    let m = Matrix::from([[0.5, -1.25, 2.], [3.1, 0.3, -0.7], [1., 1., 1.]]);
+    let v = Vector::from([1.5, -2.25, 0.1]);
+    let q16 = m.to_fixed::<16>().mul_vec(v.to_fixed());
+    let error = q16.error_against(&m.mul_vec(v.clone()));
+    println!("{} ulps, overflow {}", error.max_ulps, error.overflowed);
+    // 0.796875 ulps, overflow false
+    let big = Fixed::<16>::from_int(200);
+    println!("{} {} {}", big * big, (big * big).overflowed(), lerp(q16.clone(), v.to_fixed(), 0.5).to_f32());
+    // 32767.99998474121 true [2.631256]
+    // [0.8274994]
+    // [-0.2749939]
+    let half = Fixed::from_int(1) / Fixed::from_int(2);
+    println!("{}", Vector::lerp_fixed(q16, v.to_fixed(), half).to_f32());
+    // [2.631256]
+    // [0.8274994]
+    // [-0.2749939]
+    // cot(22.5°) as a Q16.16 constant, so no tan is needed.
+    let p = projection_fixed(Fixed::<16>::from_raw(158217), Fixed::from_int(16) / Fixed::from_int(9), Fixed::from_int(2), Fixed::from_int(100));
+    println!("{}", p.error_against(&projection(std::f32::consts::FRAC_PI_4, 16.0 / 9.0, 2.0, 100.0)).max_ulps);
+    // 0.890625
+    println!("{} {} {}", F16::from_f32(0.1), BF16::from_f32(0.1), std::mem::size_of::<F16>());
+    // 0.099975586 0.100097656 2
+    let weights = Matrix::from([[0.1, 0.2, 0.3], [1., -2., 3.5]]).to_f16();
+    let input = Vector::from([1., 1e-3, -7.]);
+    println!("{:?}", weights.mul_vec_mixed(&input).data);
+    // [-2.0001662, -23.502]
+    let ones = Vector::from(vec![1.; 4096]).to_f16();
+    println!("{}", ones.dot(ones.clone()));
+    // 4096
+    let [a, b, c, d, e, f, g, h, i] = ["a", "b", "c", "d", "e", "f", "g", "h", "i"].map(Expr::symbol);
+    let symbolic = Matrix::from([[a.clone(), b.clone()], [c.clone(), d.clone()]]);
+    println!("{}", symbolic.determinant());
+    // a*d - b*c
+    println!("{}", symbolic.inverse().unwrap());
+    // [d/(a*d - b*c), -b/(a*d - b*c)]
+    // [-c/(a*d - b*c), a/(a*d - b*c)]
+    println!("{}", symbolic.inverse().unwrap().to_latex());
+    // \begin{pmatrix}
+    // \frac{d}{a d - b c} & -\frac{b}{a d - b c} \\
+    // -\frac{c}{a d - b c} & \frac{a}{a d - b c}
+    // \end{pmatrix}
+    println!("{}", Matrix::from([[a, b, c], [d, e, f], [g, h, i]]).determinant());
+    // a*e*i - a*f*h - b*d*i + b*f*g + c*d*h - c*e*g
+}
@@ -0,0 +1,252 @@
+use crate::types::Field;
+use crate::Vector;
+use super::operator::{LinearOperator, SolverOptions, ConvergenceReport, axpy, residual, rhs_scale};
+
+/// Preconditioned Conjugate Gradient. `a` must be symmetric positive definite,
+/// and so must the preconditioner if one is given.
+pub fn conjugate_gradient<K, A>(a: &A, b: &Vector<K>, opts: &SolverOptions<K>) -> ConvergenceReport<K>
+where
+    K: Field,
+    A: LinearOperator<K> + ?Sized,
+{
+    let n = a.size();
+    b.assert_same_size(&Vector::from(vec![K::zero(); n]));
+
+    let scale = rhs_scale(b);
+    let mut x = opts.start(n);
+    let mut r = residual(a, b, &x);
+    let mut history = vec![r.norm() / scale];
+    let mut converged = history[0] <= opts.tol;
+    let mut iterations = 0;
+
+    let mut z = opts.precondition(&r);
+    let mut p = z.clone();
+    let mut rz = r.dot(z.clone());
+
+    while !converged && iterations < opts.max_iter {
+        let ap = a.apply(&p);
+        let pap = p.dot(ap.clone());
+        if pap == K::zero() {
+            break;
+        }
+
+        let alpha = rz.clone() / pap;
+        axpy(&mut x, alpha.clone(), &p);
+        axpy(&mut r, -alpha, &ap);
+        iterations += 1;
+
+        let res = r.norm() / scale;
+        history.push(res);
+        if res <= opts.tol {
+            converged = true;
+            break;
+        }
+
+        z = opts.precondition(&r);
+        let rz_new = r.dot(z.clone());
+        let beta = rz_new.clone() / rz;
+        for i in 0..n {
+            p.data[i] = z.data[i].clone() + beta.clone() * p.data[i].clone();
+        }
+        rz = rz_new;
+    }
+
+    ConvergenceReport { x, converged, iterations, residual_history: history }
+}
+
+/// Right-preconditioned BiCGSTAB for general nonsymmetric systems.
+pub fn bicgstab<K, A>(a: &A, b: &Vector<K>, opts: &SolverOptions<K>) -> ConvergenceReport<K>
+where
+    K: Field,
+    A: LinearOperator<K> + ?Sized,
+{
+    let n = a.size();
+    b.assert_same_size(&Vector::from(vec![K::zero(); n]));
+
+    let scale = rhs_scale(b);
+    let mut x = opts.start(n);
+    let mut r = residual(a, b, &x);
+    let r_hat = r.clone();
+    let mut history = vec![r.norm() / scale];
+    let mut converged = history[0] <= opts.tol;
+    let mut iterations = 0;
+
+    let mut rho = K::one();
+    let mut alpha = K::one();
+    let mut omega = K::one();
+    let mut v = Vector::from(vec![K::zero(); n]);
+    let mut p = Vector::from(vec![K::zero(); n]);
+
+    while !converged && iterations < opts.max_iter {
+        let rho_new = r_hat.dot(r.clone());
+        if rho_new == K::zero() {
+            break;
+        }
+
+        if iterations == 0 {
+            p = r.clone();
+        } else {
+            let beta = (rho_new.clone() / rho) * (alpha / omega.clone());
+            for i in 0..n {
+                p.data[i] = r.data[i].clone() + beta.clone() * (p.data[i].clone() - omega.clone() * v.data[i].clone());
+            }
+        }
+
+        let p_hat = opts.precondition(&p);
+        v = a.apply(&p_hat);
+        let rv = r_hat.dot(v.clone());
+        if rv == K::zero() {
+            break;
+        }
+        alpha = rho_new.clone() / rv;
+
+        let mut s = r.clone();
+        axpy(&mut s, -alpha.clone(), &v);
+        iterations += 1;
+
+        let s_res = s.norm() / scale;
+        if s_res <= opts.tol {
+            axpy(&mut x, alpha, &p_hat);
+            history.push(s_res);
+            converged = true;
+            break;
+        }
+
+        let s_hat = opts.precondition(&s);
+        let t = a.apply(&s_hat);
+        let tt = t.dot(t.clone());
+        if tt == K::zero() {
+            history.push(s_res);
+            break;
+        }
+        omega = t.dot(s.clone()) / tt;
+
+        axpy(&mut x, alpha.clone(), &p_hat);
+        axpy(&mut x, omega.clone(), &s_hat);
+        r = s;
+        axpy(&mut r, -omega.clone(), &t);
+
+        let res = r.norm() / scale;
+        history.push(res);
+        if res <= opts.tol {
+            converged = true;
+            break;
+        }
+        if omega == K::zero() {
+            break;
+        }
+        rho = rho_new;
+    }
+
+    ConvergenceReport { x, converged, iterations, residual_history: history }
+}
+
+/// Right-preconditioned GMRES restarted every `opts.restart` iterations.
+/// Each inner iteration counts towards `opts.max_iter`.
+pub fn gmres<K, A>(a: &A, b: &Vector<K>, opts: &SolverOptions<K>) -> ConvergenceReport<K>
+where
+    K: Field,
+    A: LinearOperator<K> + ?Sized,
+{
+    let n = a.size();
+    b.assert_same_size(&Vector::from(vec![K::zero(); n]));
+    let m = opts.restart.max(1).min(n.max(1));
+
+    let scale = rhs_scale(b);
+    let mut x = opts.start(n);
+    let mut history = Vec::new();
+    let mut converged = false;
+    let mut iterations = 0;
+
+    loop {
+        let r = residual(a, b, &x);
+        let beta = r.dot(r.clone()).sqrt();
+        let res = r.norm() / scale;
+        if history.is_empty() {
+            history.push(res);
+        }
+        if res <= opts.tol {
+            converged = true;
+            break;
+        }
+        if iterations >= opts.max_iter {
+            break;
+        }
+
+        let mut basis = Vec::with_capacity(m + 1);
+        let mut v0 = r;
+        v0.scl(K::one() / beta.clone());
+        basis.push(v0);
+
+        let mut h = vec![vec![K::zero(); m]; m + 1];
+        let mut cs = vec![K::zero(); m];
+        let mut sn = vec![K::zero(); m];
+        let mut g = vec![K::zero(); m + 1];
+        g[0] = beta;
+
+        let mut k = 0;
+        while k < m && iterations < opts.max_iter {
+            let j = k;
+            let mut w = a.apply(&opts.precondition(&basis[j]));
+
+            for i in 0..=j {
+                h[i][j] = w.dot(basis[i].clone());
+                axpy(&mut w, -h[i][j].clone(), &basis[i]);
+            }
+            let h_next = w.dot(w.clone()).sqrt();
+            h[j + 1][j] = h_next.clone();
+
+            for i in 0..j {
+                let temp = cs[i].clone() * h[i][j].clone() + sn[i].clone() * h[i + 1][j].clone();
+                h[i + 1][j] = -sn[i].clone() * h[i][j].clone() + cs[i].clone() * h[i + 1][j].clone();
+                h[i][j] = temp;
+            }
+
+            let denom = (h[j][j].clone() * h[j][j].clone() + h[j + 1][j].clone() * h[j + 1][j].clone()).sqrt();
+            if denom == K::zero() {
+                break;
+            }
+            cs[j] = h[j][j].clone() / denom.clone();
+            sn[j] = h[j + 1][j].clone() / denom.clone();
+            h[j][j] = denom;
+            h[j + 1][j] = K::zero();
+            g[j + 1] = -sn[j].clone() * g[j].clone();
+            g[j] = cs[j].clone() * g[j].clone();
+
+            k += 1;
+            iterations += 1;
+
+            let g_next: f32 = g[j + 1].clone().into();
+            let res = g_next.abs() / scale;
+            history.push(res);
+            if res <= opts.tol || h_next == K::zero() {
+                break;
+            }
+
+            let mut next = w;
+            next.scl(K::one() / h_next);
+            basis.push(next);
+        }
+
+        if k == 0 {
+            break;
+        }
+
+        let mut y = vec![K::zero(); k];
+        for i in (0..k).rev() {
+            let mut sum = g[i].clone();
+            for l in i + 1..k {
+                sum -= h[i][l].clone() * y[l].clone();
+            }
+            y[i] = sum / h[i][i].clone();
+        }
+
+        let mut update = Vector::from(vec![K::zero(); n]);
+        for i in 0..k {
+            axpy(&mut update, y[i].clone(), &basis[i]);
+        }
+        x.add(opts.precondition(&update));
+    }
+
+    ConvergenceReport { x, converged, iterations, residual_history: history }
+}
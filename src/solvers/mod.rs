@@ -0,0 +1,16 @@
+pub mod operator;
+pub mod krylov;
+pub mod stationary;
+pub mod preconditioner;
+
+pub use operator::LinearOperator;
+pub use operator::Preconditioner;
+pub use operator::IdentityPreconditioner;
+pub use operator::SolverOptions;
+pub use operator::ConvergenceReport;
+pub use krylov::conjugate_gradient;
+pub use krylov::bicgstab;
+pub use krylov::gmres;
+pub use preconditioner::JacobiPreconditioner;
+pub use preconditioner::Ilu0;
+pub use preconditioner::IncompleteCholesky;
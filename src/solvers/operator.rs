@@ -0,0 +1,134 @@
+use crate::types::Field;
+use crate::{Matrix, Vector};
+
+/// A square operator `x -> A x`; the solvers only ever need its action on a vector.
+pub trait LinearOperator<K: Field> {
+    fn size(&self) -> usize;
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K>;
+}
+
+impl<K: Field> LinearOperator<K> for Matrix<K> {
+    fn size(&self) -> usize {
+        assert!(self.is_square(), "LinearOperator requires a square matrix, got {:?}", self.shape());
+        self.rows
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x.clone())
+    }
+}
+
+/// Approximates `M^-1 r` for some `M` close to `A`.
+pub trait Preconditioner<K: Field> {
+    fn apply(&self, r: &Vector<K>) -> Vector<K>;
+}
+
+pub struct IdentityPreconditioner;
+
+impl<K: Field> Preconditioner<K> for IdentityPreconditioner {
+    fn apply(&self, r: &Vector<K>) -> Vector<K> {
+        r.clone()
+    }
+}
+
+pub struct SolverOptions<'a, K: Field> {
+    pub tol: f32,
+    pub max_iter: usize,
+    pub restart: usize,
+    pub x0: Option<Vector<K>>,
+    pub preconditioner: Option<&'a dyn Preconditioner<K>>,
+}
+
+impl<'a, K: Field> SolverOptions<'a, K> {
+    pub fn new() -> Self {
+        Self {
+            tol: 1e-6,
+            max_iter: 1000,
+            restart: 30,
+            x0: None,
+            preconditioner: None,
+        }
+    }
+
+    pub fn tol(mut self, tol: f32) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+    /// Krylov subspace size before GMRES restarts; ignored by the other solvers.
+    pub fn restart(mut self, restart: usize) -> Self {
+        self.restart = restart;
+        self
+    }
+
+    pub fn initial_guess(mut self, x0: Vector<K>) -> Self {
+        self.x0 = Some(x0);
+        self
+    }
+
+    pub fn preconditioner(mut self, m: &'a dyn Preconditioner<K>) -> Self {
+        self.preconditioner = Some(m);
+        self
+    }
+
+    pub(crate) fn start(&self, n: usize) -> Vector<K> {
+        match &self.x0 {
+            Some(x0) => {
+                assert_eq!(x0.size(), n, "Initial guess size mismatch: {} vs {}", x0.size(), n);
+                x0.clone()
+            }
+            None => Vector::from(vec![K::zero(); n]),
+        }
+    }
+
+    pub(crate) fn precondition(&self, r: &Vector<K>) -> Vector<K> {
+        match self.preconditioner {
+            Some(m) => m.apply(r),
+            None => r.clone(),
+        }
+    }
+}
+
+impl<'a, K: Field> Default for SolverOptions<'a, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Residuals in `residual_history` are relative, `||b - A x|| / ||b||`, starting with the initial guess.
+#[derive(Debug, Clone)]
+pub struct ConvergenceReport<K: Field> {
+    pub x: Vector<K>,
+    pub converged: bool,
+    pub iterations: usize,
+    pub residual_history: Vec<f32>,
+}
+
+impl<K: Field> ConvergenceReport<K> {
+    pub fn residual(&self) -> f32 {
+        *self.residual_history.last().unwrap_or(&f32::INFINITY)
+    }
+}
+
+pub(crate) fn axpy<K: Field>(y: &mut Vector<K>, a: K, x: &Vector<K>) {
+    for i in 0..y.data.len() {
+        y.data[i] += a.clone() * x.data[i].clone();
+    }
+}
+
+pub(crate) fn residual<K: Field, A: LinearOperator<K> + ?Sized>(a: &A, b: &Vector<K>, x: &Vector<K>) -> Vector<K> {
+    let mut r = b.clone();
+    r.sub(a.apply(x));
+    r
+}
+
+pub(crate) fn rhs_scale<K: Field>(b: &Vector<K>) -> f32 {
+    let norm = b.norm();
+    if norm > 0.0 { norm } else { 1.0 }
+}
@@ -0,0 +1,177 @@
+use crate::types::Field;
+use crate::{Matrix, Vector};
+use super::operator::Preconditioner;
+
+/// Diagonal (Jacobi) preconditioner: M = diag(A). Cheap, and often enough when
+/// the rows of A have very different scales.
+pub struct JacobiPreconditioner<K: Field> {
+    inv_diag: Vec<K>,
+}
+
+impl<K: Field> JacobiPreconditioner<K> {
+    pub fn new(a: &Matrix<K>) -> Result<Self, String> {
+        if !a.is_square() {
+            return Err("Matrix must be square".to_string());
+        }
+        let mut inv_diag = Vec::with_capacity(a.rows);
+        for i in 0..a.rows {
+            if a.data[i][i] == K::zero() {
+                return Err(format!("Zero on the diagonal at row {}", i));
+            }
+            inv_diag.push(K::one() / a.data[i][i].clone());
+        }
+        Ok(Self { inv_diag })
+    }
+}
+
+impl<K: Field> Preconditioner<K> for JacobiPreconditioner<K> {
+    fn apply(&self, r: &Vector<K>) -> Vector<K> {
+        let mut z = r.clone();
+        for i in 0..z.data.len() {
+            z.data[i] *= self.inv_diag[i].clone();
+        }
+        z
+    }
+}
+
+/// Incomplete LU with zero fill-in: Gaussian elimination that only ever writes
+/// to positions where A itself is nonzero, so L + U keeps the sparsity of A.
+/// L is unit lower triangular and shares storage with U.
+pub struct Ilu0<K: Field> {
+    lu: Matrix<K>,
+}
+
+impl<K: Field> Ilu0<K> {
+    pub fn new(a: &Matrix<K>) -> Result<Self, String> {
+        if !a.is_square() {
+            return Err("Matrix must be square".to_string());
+        }
+        let n = a.rows;
+        let mut lu = a.clone();
+
+        for i in 1..n {
+            for k in 0..i {
+                if a.data[i][k] == K::zero() {
+                    continue;
+                }
+                if lu.data[k][k] == K::zero() {
+                    return Err(format!("Zero pivot at row {}", k));
+                }
+                let factor = lu.data[i][k].clone() / lu.data[k][k].clone();
+                lu.data[i][k] = factor.clone();
+                for j in k + 1..n {
+                    if a.data[i][j] != K::zero() {
+                        let u_kj = lu.data[k][j].clone();
+                        lu.data[i][j] -= factor.clone() * u_kj;
+                    }
+                }
+            }
+        }
+        for i in 0..n {
+            if lu.data[i][i] == K::zero() {
+                return Err(format!("Zero pivot at row {}", i));
+            }
+        }
+
+        Ok(Self { lu })
+    }
+}
+
+impl<K: Field> Preconditioner<K> for Ilu0<K> {
+    fn apply(&self, r: &Vector<K>) -> Vector<K> {
+        let n = self.lu.rows;
+        let mut z = r.clone();
+
+        for i in 0..n {
+            for j in 0..i {
+                let l_ij = self.lu.data[i][j].clone();
+                let z_j = z.data[j].clone();
+                z.data[i] -= l_ij * z_j;
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let u_ij = self.lu.data[i][j].clone();
+                let z_j = z.data[j].clone();
+                z.data[i] -= u_ij * z_j;
+            }
+            z.data[i] = z.data[i].clone() / self.lu.data[i][i].clone();
+        }
+        z
+    }
+}
+
+/// Incomplete Cholesky with zero fill-in, IC(0): A ~ L L^T with L restricted to
+/// the lower-triangular pattern of A. Only for symmetric positive definite A,
+/// which makes it the natural partner of `conjugate_gradient`.
+pub struct IncompleteCholesky<K: Field> {
+    l: Matrix<K>,
+}
+
+impl<K: Field + PartialOrd> IncompleteCholesky<K> {
+    pub fn new(a: &Matrix<K>) -> Result<Self, String> {
+        if !a.is_square() {
+            return Err("Matrix must be square".to_string());
+        }
+        let n = a.rows;
+        let mut l = Matrix::from(vec![vec![K::zero(); n]; n]);
+        for i in 0..n {
+            for j in 0..=i {
+                l.data[i][j] = a.data[i][j].clone();
+            }
+        }
+
+        for k in 0..n {
+            if l.data[k][k] <= K::zero() {
+                return Err(format!("Matrix is not positive definite (pivot {})", k));
+            }
+            l.data[k][k] = l.data[k][k].clone().sqrt();
+            let pivot = l.data[k][k].clone();
+
+            for i in k + 1..n {
+                if l.data[i][k] != K::zero() {
+                    l.data[i][k] = l.data[i][k].clone() / pivot.clone();
+                }
+            }
+            for j in k + 1..n {
+                let l_jk = l.data[j][k].clone();
+                if l_jk == K::zero() {
+                    continue;
+                }
+                for i in j..n {
+                    if a.data[i][j] != K::zero() {
+                        let l_ik = l.data[i][k].clone();
+                        l.data[i][j] -= l_ik * l_jk.clone();
+                    }
+                }
+            }
+        }
+
+        Ok(Self { l })
+    }
+}
+
+impl<K: Field> Preconditioner<K> for IncompleteCholesky<K> {
+    fn apply(&self, r: &Vector<K>) -> Vector<K> {
+        let n = self.l.rows;
+        let mut z = r.clone();
+
+        for i in 0..n {
+            for j in 0..i {
+                let l_ij = self.l.data[i][j].clone();
+                let z_j = z.data[j].clone();
+                z.data[i] -= l_ij * z_j;
+            }
+            z.data[i] = z.data[i].clone() / self.l.data[i][i].clone();
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let l_ji = self.l.data[j][i].clone();
+                let z_j = z.data[j].clone();
+                z.data[i] -= l_ji * z_j;
+            }
+            z.data[i] = z.data[i].clone() / self.l.data[i][i].clone();
+        }
+        z
+    }
+}
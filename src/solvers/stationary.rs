@@ -0,0 +1,80 @@
+use crate::types::Field;
+use crate::{Matrix, Vector};
+use super::operator::{SolverOptions, ConvergenceReport, residual, rhs_scale};
+
+// The classical splitting methods write A = D + L + U (diagonal, strictly lower,
+// strictly upper) and iterate x <- M^-1 (b - N x) for some easy-to-invert M.
+// They converge for strictly diagonally dominant matrices, and Gauss-Seidel/SOR
+// (with 0 < omega < 2) also for symmetric positive definite ones.
+impl<K: Field> Matrix<K> {
+    /// Jacobi iteration, M = D: every component of the new iterate is computed
+    /// from the previous iterate only.
+    pub fn jacobi(&self, b: &Vector<K>, opts: &SolverOptions<K>) -> ConvergenceReport<K> {
+        self.stationary(b, opts, |a, b, x| {
+            let old = x.clone();
+            for i in 0..a.rows {
+                let mut sum = b.data[i].clone();
+                for j in 0..a.cols {
+                    if j != i {
+                        sum -= a.data[i][j].clone() * old.data[j].clone();
+                    }
+                }
+                x.data[i] = sum / a.data[i][i].clone();
+            }
+        })
+    }
+
+    /// Gauss-Seidel iteration, M = D + L: components updated earlier in the
+    /// sweep are used immediately. Same as `sor` with omega = 1.
+    pub fn gauss_seidel(&self, b: &Vector<K>, opts: &SolverOptions<K>) -> ConvergenceReport<K> {
+        self.sor(b, K::one(), opts)
+    }
+
+    /// Successive over-relaxation: a Gauss-Seidel sweep whose update is scaled
+    /// by `omega`. omega > 1 over-relaxes and can converge much faster.
+    pub fn sor(&self, b: &Vector<K>, omega: K, opts: &SolverOptions<K>) -> ConvergenceReport<K> {
+        self.stationary(b, opts, |a, b, x| {
+            for i in 0..a.rows {
+                let mut sum = b.data[i].clone();
+                for j in 0..a.cols {
+                    if j != i {
+                        sum -= a.data[i][j].clone() * x.data[j].clone();
+                    }
+                }
+                let gs = sum / a.data[i][i].clone();
+                x.data[i] = x.data[i].clone() + omega.clone() * (gs - x.data[i].clone());
+            }
+        })
+    }
+
+    fn stationary<F>(&self, b: &Vector<K>, opts: &SolverOptions<K>, mut sweep: F) -> ConvergenceReport<K>
+    where
+        F: FnMut(&Matrix<K>, &Vector<K>, &mut Vector<K>),
+    {
+        assert!(self.is_square(), "Stationary solvers require a square matrix, got {:?}", self.shape());
+        assert_eq!(self.rows, b.size(), "Right-hand side size mismatch: {} vs {}", self.rows, b.size());
+        for i in 0..self.rows {
+            assert!(self.data[i][i] != K::zero(), "Zero on the diagonal at row {}", i);
+        }
+
+        let scale = rhs_scale(b);
+        let mut x = opts.start(self.rows);
+        let mut history = vec![residual(self, b, &x).norm() / scale];
+        let mut converged = history[0] <= opts.tol;
+        let mut iterations = 0;
+
+        while !converged && iterations < opts.max_iter {
+            sweep(self, b, &mut x);
+            iterations += 1;
+
+            let res = residual(self, b, &x).norm() / scale;
+            history.push(res);
+            if !res.is_finite() {
+                break;
+            }
+            converged = res <= opts.tol;
+        }
+
+        ConvergenceReport { x, converged, iterations, residual_history: history }
+    }
+}
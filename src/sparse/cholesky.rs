@@ -0,0 +1,249 @@
+use crate::types::Field;
+use crate::Vector;
+use super::matrix::{SparseMatrix, SparseStorage};
+use super::ordering::{FillOrdering, fill_ordering, invert_permutation};
+
+const NONE: usize = usize::MAX;
+
+/// Everything about P A P^T = L L^T that depends only on the sparsity pattern:
+/// the permutation, the elimination tree and the column layout of L.
+/// Compute it once and call `factor` for every matrix sharing the pattern.
+#[derive(Debug, Clone)]
+pub struct SymbolicCholesky {
+    pub n: usize,
+    pub perm: Vec<usize>,
+    pinv: Vec<usize>,
+    parent: Vec<usize>,
+    l_ptr: Vec<usize>,
+    pattern: (Vec<usize>, Vec<usize>),
+}
+
+/// Numeric Cholesky factor, L stored by columns with the diagonal first.
+#[derive(Debug, Clone)]
+pub struct SparseCholesky<K: Field> {
+    pub symbolic: SymbolicCholesky,
+    pub l: SparseMatrix<K>,
+}
+
+impl SymbolicCholesky {
+    /// Analyses a symmetric matrix; only the upper triangle of P A P^T is read.
+    pub fn analyze<K: Field>(a: &SparseMatrix<K>, ordering: FillOrdering) -> Result<Self, String> {
+        if !a.is_square() {
+            return Err("Matrix must be square".to_string());
+        }
+        let n = a.rows;
+        let perm = fill_ordering(a, ordering);
+        let pinv = invert_permutation(&perm);
+        let c = permute_upper(a, &pinv);
+        let (c_ptr, c_idx) = csc_pattern(&c);
+
+        let parent = etree(n, c_ptr, c_idx);
+
+        // Row k of L is the reach of column k of C in the elimination tree.
+        let mut counts = vec![1; n];
+        let mut stack = vec![0; n];
+        let mut mark = vec![NONE; n];
+        for k in 0..n {
+            let top = ereach(k, c_ptr, c_idx, &parent, &mut stack, &mut mark);
+            for &i in &stack[top..] {
+                counts[i] += 1;
+            }
+        }
+        let mut l_ptr = vec![0; n + 1];
+        for i in 0..n {
+            l_ptr[i + 1] = l_ptr[i] + counts[i];
+        }
+
+        let csc = a.to_csc();
+        let (a_ptr, a_idx) = csc_pattern(&csc);
+        Ok(SymbolicCholesky { n, perm, pinv, parent, l_ptr, pattern: (a_ptr.to_vec(), a_idx.to_vec()) })
+    }
+
+    pub fn nnz_l(&self) -> usize {
+        self.l_ptr[self.n]
+    }
+
+    /// Numeric factorization of a matrix with the analysed pattern.
+    pub fn factor<K: Field + PartialOrd>(&self, a: &SparseMatrix<K>) -> Result<SparseCholesky<K>, String> {
+        let csc = a.to_csc();
+        let (a_ptr, a_idx) = csc_pattern(&csc);
+        if a.shape() != (self.n, self.n) || a_ptr != self.pattern.0.as_slice() || a_idx != self.pattern.1.as_slice() {
+            return Err("Sparsity pattern differs from the analysed one".to_string());
+        }
+
+        let n = self.n;
+        let c = permute_upper(a, &self.pinv);
+        let (c_ptr, c_idx) = csc_pattern(&c);
+        let c_val = csc_values(&c);
+
+        let nnz = self.nnz_l();
+        let mut l_idx = vec![0; nnz];
+        let mut l_val = vec![K::zero(); nnz];
+        let mut next = self.l_ptr[..n].to_vec();
+        let mut x = vec![K::zero(); n];
+        let mut stack = vec![0; n];
+        let mut mark = vec![NONE; n];
+
+        // Up-looking: row k of L comes from a sparse triangular solve with the
+        // rows already computed, restricted to the etree reach of column k.
+        for k in 0..n {
+            let top = ereach(k, c_ptr, c_idx, &self.parent, &mut stack, &mut mark);
+            x[k] = K::zero();
+            for p in c_ptr[k]..c_ptr[k + 1] {
+                if c_idx[p] <= k {
+                    x[c_idx[p]] = c_val[p].clone();
+                }
+            }
+            let mut d = x[k].clone();
+            x[k] = K::zero();
+
+            for &i in &stack[top..] {
+                let l_ki = x[i].clone() / l_val[self.l_ptr[i]].clone();
+                x[i] = K::zero();
+                for p in self.l_ptr[i] + 1..next[i] {
+                    x[l_idx[p]] -= l_val[p].clone() * l_ki.clone();
+                }
+                d -= l_ki.clone() * l_ki.clone();
+                let p = next[i];
+                next[i] += 1;
+                l_idx[p] = k;
+                l_val[p] = l_ki;
+            }
+
+            if d <= K::zero() {
+                return Err(format!("Matrix is not positive definite (pivot {})", k));
+            }
+            let p = next[k];
+            next[k] += 1;
+            l_idx[p] = k;
+            l_val[p] = d.sqrt();
+        }
+
+        let l = SparseMatrix {
+            rows: n,
+            cols: n,
+            storage: SparseStorage::Csc { col_ptr: self.l_ptr.clone(), row_idx: l_idx, values: l_val },
+        };
+        Ok(SparseCholesky { symbolic: self.clone(), l })
+    }
+}
+
+impl<K: Field + PartialOrd> SparseCholesky<K> {
+    pub fn new(a: &SparseMatrix<K>, ordering: FillOrdering) -> Result<Self, String> {
+        SymbolicCholesky::analyze(a, ordering)?.factor(a)
+    }
+
+    /// Numeric-only refactorization for new values on the same pattern.
+    pub fn refactor(&mut self, a: &SparseMatrix<K>) -> Result<(), String> {
+        *self = self.symbolic.factor(a)?;
+        Ok(())
+    }
+
+    pub fn solve(&self, b: &Vector<K>) -> Vector<K> {
+        let n = self.symbolic.n;
+        assert_eq!(b.size(), n, "Right-hand side size mismatch: {} vs {}", b.size(), n);
+        let (l_ptr, l_idx) = csc_pattern(&self.l);
+        let l_val = csc_values(&self.l);
+        let perm = &self.symbolic.perm;
+
+        let mut y: Vec<K> = (0..n).map(|k| b.data[perm[k]].clone()).collect();
+        for j in 0..n {
+            y[j] = y[j].clone() / l_val[l_ptr[j]].clone();
+            let y_j = y[j].clone();
+            for p in l_ptr[j] + 1..l_ptr[j + 1] {
+                y[l_idx[p]] -= l_val[p].clone() * y_j.clone();
+            }
+        }
+        for j in (0..n).rev() {
+            for p in l_ptr[j] + 1..l_ptr[j + 1] {
+                let y_i = y[l_idx[p]].clone();
+                y[j] -= l_val[p].clone() * y_i;
+            }
+            y[j] = y[j].clone() / l_val[l_ptr[j]].clone();
+        }
+
+        let mut x = vec![K::zero(); n];
+        for k in 0..n {
+            x[perm[k]] = y[k].clone();
+        }
+        Vector::from(x)
+    }
+}
+
+// Upper triangle of P A P^T in CSC; entries below the diagonal of A are
+// mirrored so that either triangle (or both) may be supplied.
+fn permute_upper<K: Field>(a: &SparseMatrix<K>, pinv: &[usize]) -> SparseMatrix<K> {
+    let a = a.to_csc();
+    let (a_ptr, a_idx) = csc_pattern(&a);
+    let mut c = SparseMatrix::new(a.rows, a.cols);
+    for (i, j, v) in a.triplets() {
+        // When both (i, j) and (j, i) are stored, keep the upper one only.
+        let mirrored = a_idx[a_ptr[i]..a_ptr[i + 1]].binary_search(&j).is_ok();
+        if i <= j || !mirrored {
+            let (pi, pj) = (pinv[i], pinv[j]);
+            c.push(pi.min(pj), pi.max(pj), v);
+        }
+    }
+    c.to_csc()
+}
+
+pub(crate) fn csc_pattern<K: Field>(a: &SparseMatrix<K>) -> (&[usize], &[usize]) {
+    match &a.storage {
+        SparseStorage::Csc { col_ptr, row_idx, .. } => (col_ptr, row_idx),
+        _ => panic!("Expected a CSC matrix"),
+    }
+}
+
+pub(crate) fn csc_values<K: Field>(a: &SparseMatrix<K>) -> &[K] {
+    match &a.storage {
+        SparseStorage::Csc { values, .. } => values,
+        _ => panic!("Expected a CSC matrix"),
+    }
+}
+
+// Elimination tree of a symmetric matrix given its upper triangle, with path
+// compression through `ancestor` (Liu's algorithm).
+#[allow(clippy::needless_range_loop)]
+fn etree(n: usize, c_ptr: &[usize], c_idx: &[usize]) -> Vec<usize> {
+    let mut parent = vec![NONE; n];
+    let mut ancestor = vec![NONE; n];
+    for k in 0..n {
+        for p in c_ptr[k]..c_ptr[k + 1] {
+            let mut i = c_idx[p];
+            while i != NONE && i < k {
+                let next = ancestor[i];
+                ancestor[i] = k;
+                if next == NONE {
+                    parent[i] = k;
+                }
+                i = next;
+            }
+        }
+    }
+    parent
+}
+
+// Nonzero pattern of row k of L, written to stack[top..] in topological order.
+#[allow(clippy::needless_range_loop)]
+fn ereach(k: usize, c_ptr: &[usize], c_idx: &[usize], parent: &[usize], stack: &mut [usize], mark: &mut [usize]) -> usize {
+    let n = stack.len();
+    let mut top = n;
+    mark[k] = k;
+    let mut path = Vec::new();
+    for p in c_ptr[k]..c_ptr[k + 1] {
+        let mut i = c_idx[p];
+        if i > k {
+            continue;
+        }
+        while mark[i] != k {
+            path.push(i);
+            mark[i] = k;
+            i = parent[i];
+        }
+        while let Some(i) = path.pop() {
+            top -= 1;
+            stack[top] = i;
+        }
+    }
+    top
+}
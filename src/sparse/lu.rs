@@ -0,0 +1,286 @@
+use crate::types::Field;
+use crate::Vector;
+use super::matrix::{SparseMatrix, SparseStorage};
+use super::ordering::{FillOrdering, fill_ordering};
+use super::cholesky::{csc_pattern, csc_values};
+
+const NONE: usize = usize::MAX;
+
+// A diagonal entry is kept as pivot when it is at least this fraction of the
+// largest candidate, which preserves the fill-reducing column ordering.
+const PIVOT_TOLERANCE: f32 = 0.1;
+
+/// Column ordering for P A Q = L U, computed from the pattern of A + A^T.
+#[derive(Debug, Clone)]
+pub struct SymbolicLu {
+    pub n: usize,
+    pub q: Vec<usize>,
+    pattern: (Vec<usize>, Vec<usize>),
+}
+
+/// L is unit lower triangular with its diagonal stored first in each column,
+/// U keeps its diagonal last. Both are CSC with rows in pivot order.
+#[derive(Debug, Clone)]
+pub struct SparseLu<K: Field> {
+    pub symbolic: SymbolicLu,
+    pub pinv: Vec<usize>,
+    pub l: SparseMatrix<K>,
+    pub u: SparseMatrix<K>,
+}
+
+impl SymbolicLu {
+    pub fn analyze<K: Field>(a: &SparseMatrix<K>, ordering: FillOrdering) -> Result<Self, String> {
+        if !a.is_square() {
+            return Err("Matrix must be square".to_string());
+        }
+        let q = fill_ordering(a, ordering);
+        let csc = a.to_csc();
+        let (a_ptr, a_idx) = csc_pattern(&csc);
+        Ok(SymbolicLu { n: a.rows, q, pattern: (a_ptr.to_vec(), a_idx.to_vec()) })
+    }
+
+    /// Left-looking Gilbert-Peierls factorization with threshold partial pivoting.
+    pub fn factor<K: Field>(&self, a: &SparseMatrix<K>) -> Result<SparseLu<K>, String> {
+        let csc = self.check_pattern(a)?;
+        let (a_ptr, a_idx) = csc_pattern(&csc);
+        let a_val = csc_values(&csc);
+        let n = self.n;
+
+        let mut pinv = vec![NONE; n];
+        let (mut l_ptr, mut l_idx, mut l_val) = (vec![0], Vec::new(), Vec::<K>::new());
+        let (mut u_ptr, mut u_idx, mut u_val) = (vec![0], Vec::new(), Vec::new());
+        let mut x = vec![K::zero(); n];
+        let mut xi = vec![0; n];
+        let mut marked = vec![false; n];
+
+        for k in 0..n {
+            let col = self.q[k];
+
+            // Pattern of L^-1 A(:, col), then the sparse triangular solve itself.
+            let mut top = n;
+            for p in a_ptr[col]..a_ptr[col + 1] {
+                if !marked[a_idx[p]] {
+                    top = dfs(a_idx[p], &l_ptr, &l_idx, &pinv, top, &mut xi, &mut marked);
+                }
+            }
+            for &i in &xi[top..] {
+                marked[i] = false;
+                x[i] = K::zero();
+            }
+            for p in a_ptr[col]..a_ptr[col + 1] {
+                x[a_idx[p]] = a_val[p].clone();
+            }
+            for &j in &xi[top..] {
+                let jj = pinv[j];
+                if jj == NONE {
+                    continue;
+                }
+                let x_j = x[j].clone();
+                for p in l_ptr[jj] + 1..l_ptr[jj + 1] {
+                    x[l_idx[p]] -= l_val[p].clone() * x_j.clone();
+                }
+            }
+
+            let mut ipiv = NONE;
+            let mut best = 0.0f32;
+            for &i in &xi[top..] {
+                if pinv[i] == NONE {
+                    let mag = magnitude(x[i].clone());
+                    if ipiv == NONE || mag > best {
+                        best = mag;
+                        ipiv = i;
+                    }
+                } else {
+                    u_idx.push(pinv[i]);
+                    u_val.push(x[i].clone());
+                }
+            }
+            if ipiv == NONE || best == 0.0 {
+                return Err("Matrix is singular".to_string());
+            }
+            if pinv[col] == NONE && magnitude(x[col].clone()) >= PIVOT_TOLERANCE * best {
+                ipiv = col;
+            }
+
+            let pivot = x[ipiv].clone();
+            u_idx.push(k);
+            u_val.push(pivot.clone());
+            u_ptr.push(u_idx.len());
+            pinv[ipiv] = k;
+
+            l_idx.push(ipiv);
+            l_val.push(K::one());
+            for &i in &xi[top..] {
+                if pinv[i] == NONE {
+                    l_idx.push(i);
+                    l_val.push(x[i].clone() / pivot.clone());
+                }
+                x[i] = K::zero();
+            }
+            l_ptr.push(l_idx.len());
+        }
+
+        for i in l_idx.iter_mut() {
+            *i = pinv[*i];
+        }
+        sort_columns(&l_ptr, &mut l_idx, &mut l_val);
+        sort_columns(&u_ptr, &mut u_idx, &mut u_val);
+
+        Ok(SparseLu {
+            symbolic: self.clone(),
+            pinv,
+            l: SparseMatrix { rows: n, cols: n, storage: SparseStorage::Csc { col_ptr: l_ptr, row_idx: l_idx, values: l_val } },
+            u: SparseMatrix { rows: n, cols: n, storage: SparseStorage::Csc { col_ptr: u_ptr, row_idx: u_idx, values: u_val } },
+        })
+    }
+
+    fn check_pattern<K: Field>(&self, a: &SparseMatrix<K>) -> Result<SparseMatrix<K>, String> {
+        let csc = a.to_csc();
+        let (a_ptr, a_idx) = csc_pattern(&csc);
+        if a.shape() != (self.n, self.n) || a_ptr != self.pattern.0.as_slice() || a_idx != self.pattern.1.as_slice() {
+            return Err("Sparsity pattern differs from the analysed one".to_string());
+        }
+        Ok(csc)
+    }
+}
+
+impl<K: Field> SparseLu<K> {
+    pub fn new(a: &SparseMatrix<K>, ordering: FillOrdering) -> Result<Self, String> {
+        SymbolicLu::analyze(a, ordering)?.factor(a)
+    }
+
+    /// Numeric-only refactorization: reuses the pivot sequence and the L/U
+    /// patterns of the previous factorization. Fails on a zero pivot, in which
+    /// case a fresh `symbolic.factor` is needed to pick new pivots.
+    pub fn refactor(&mut self, a: &SparseMatrix<K>) -> Result<(), String> {
+        let csc = self.symbolic.check_pattern(a)?;
+        let (a_ptr, a_idx) = csc_pattern(&csc);
+        let a_val = csc_values(&csc);
+        let n = self.symbolic.n;
+        let pinv = &self.pinv;
+
+        let (l_ptr, l_idx, l_val) = csc_parts_mut(&mut self.l);
+        let (u_ptr, u_idx, u_val) = csc_parts_mut(&mut self.u);
+        let mut x = vec![K::zero(); n];
+
+        for k in 0..n {
+            let col = self.symbolic.q[k];
+            for p in a_ptr[col]..a_ptr[col + 1] {
+                x[pinv[a_idx[p]]] = a_val[p].clone();
+            }
+
+            let diag = u_ptr[k + 1] - 1;
+            for p in u_ptr[k]..diag {
+                let j = u_idx[p];
+                let x_j = x[j].clone();
+                u_val[p] = x_j.clone();
+                x[j] = K::zero();
+                for t in l_ptr[j] + 1..l_ptr[j + 1] {
+                    x[l_idx[t]] -= l_val[t].clone() * x_j.clone();
+                }
+            }
+
+            let pivot = x[k].clone();
+            x[k] = K::zero();
+            if pivot == K::zero() {
+                return Err(format!("Zero pivot at step {}", k));
+            }
+            u_val[diag] = pivot.clone();
+            for p in l_ptr[k] + 1..l_ptr[k + 1] {
+                let i = l_idx[p];
+                l_val[p] = x[i].clone() / pivot.clone();
+                x[i] = K::zero();
+            }
+        }
+        Ok(())
+    }
+
+    pub fn solve(&self, b: &Vector<K>) -> Vector<K> {
+        let n = self.symbolic.n;
+        assert_eq!(b.size(), n, "Right-hand side size mismatch: {} vs {}", b.size(), n);
+        let (l_ptr, l_idx) = csc_pattern(&self.l);
+        let l_val = csc_values(&self.l);
+        let (u_ptr, u_idx) = csc_pattern(&self.u);
+        let u_val = csc_values(&self.u);
+
+        let mut y = vec![K::zero(); n];
+        for i in 0..n {
+            y[self.pinv[i]] = b.data[i].clone();
+        }
+        for j in 0..n {
+            let y_j = y[j].clone();
+            for p in l_ptr[j] + 1..l_ptr[j + 1] {
+                y[l_idx[p]] -= l_val[p].clone() * y_j.clone();
+            }
+        }
+        for j in (0..n).rev() {
+            let diag = u_ptr[j + 1] - 1;
+            y[j] = y[j].clone() / u_val[diag].clone();
+            let y_j = y[j].clone();
+            for p in u_ptr[j]..diag {
+                y[u_idx[p]] -= u_val[p].clone() * y_j.clone();
+            }
+        }
+
+        let mut x = vec![K::zero(); n];
+        for k in 0..n {
+            x[self.symbolic.q[k]] = y[k].clone();
+        }
+        Vector::from(x)
+    }
+}
+
+fn magnitude<K: Field>(v: K) -> f32 {
+    v.abs().into()
+}
+
+// Depth-first search in the graph of L from node j; finished nodes are pushed
+// onto xi[..top] so that xi[top..] ends up in topological order.
+fn dfs(j: usize, l_ptr: &[usize], l_idx: &[usize], pinv: &[usize], mut top: usize, xi: &mut [usize], marked: &mut [bool]) -> usize {
+    let column = |node: usize| if pinv[node] == NONE { (0, 0) } else { (l_ptr[pinv[node]], l_ptr[pinv[node] + 1]) };
+    let mut stack = vec![(j, column(j).0)];
+    marked[j] = true;
+
+    while let Some(&mut (node, ref mut p)) = stack.last_mut() {
+        let end = column(node).1;
+        let mut child = None;
+        while *p < end {
+            let i = l_idx[*p];
+            *p += 1;
+            if !marked[i] {
+                child = Some(i);
+                break;
+            }
+        }
+        match child {
+            Some(i) => {
+                marked[i] = true;
+                stack.push((i, column(i).0));
+            }
+            None => {
+                stack.pop();
+                top -= 1;
+                xi[top] = node;
+            }
+        }
+    }
+    top
+}
+
+fn sort_columns<K: Field>(ptr: &[usize], idx: &mut [usize], val: &mut [K]) {
+    for c in 0..ptr.len() - 1 {
+        let mut entries: Vec<(usize, K)> = (ptr[c]..ptr[c + 1]).map(|p| (idx[p], val[p].clone())).collect();
+        entries.sort_by_key(|e| e.0);
+        for (offset, (i, v)) in entries.into_iter().enumerate() {
+            idx[ptr[c] + offset] = i;
+            val[ptr[c] + offset] = v;
+        }
+    }
+}
+
+fn csc_parts_mut<K: Field>(a: &mut SparseMatrix<K>) -> (&[usize], &[usize], &mut [K]) {
+    match &mut a.storage {
+        SparseStorage::Csc { col_ptr, row_idx, values } => (col_ptr, row_idx, values),
+        _ => panic!("Expected a CSC matrix"),
+    }
+}
@@ -0,0 +1,375 @@
+use crate::types::Field;
+use crate::{Matrix, Vector};
+use crate::solvers::LinearOperator;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparseFormat {
+    Coo,
+    Csr,
+    Csc,
+}
+
+/// COO keeps unsorted triplets and is cheap to push into; CSR/CSC keep one
+/// sorted, duplicate-free index list per row/column behind an offset array.
+/// Explicit zeros are kept, so a sparsity pattern survives numeric updates.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SparseStorage<K: Field> {
+    Coo { row_idx: Vec<usize>, col_idx: Vec<usize>, values: Vec<K> },
+    Csr { row_ptr: Vec<usize>, col_idx: Vec<usize>, values: Vec<K> },
+    Csc { col_ptr: Vec<usize>, row_idx: Vec<usize>, values: Vec<K> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMatrix<K: Field> {
+    pub rows: usize,
+    pub cols: usize,
+    pub storage: SparseStorage<K>,
+}
+
+impl<K: Field> SparseMatrix<K> {
+
+    pub fn new(rows: usize, cols: usize) -> Self {
+        SparseMatrix {
+            rows,
+            cols,
+            storage: SparseStorage::Coo { row_idx: Vec::new(), col_idx: Vec::new(), values: Vec::new() },
+        }
+    }
+
+    pub fn from_triplets(rows: usize, cols: usize, triplets: &[(usize, usize, K)]) -> Self {
+        let mut m = Self::new(rows, cols);
+        for (i, j, v) in triplets {
+            m.push(*i, *j, v.clone());
+        }
+        m
+    }
+
+    pub fn from_dense(dense: &Matrix<K>) -> Self {
+        let mut row_ptr = vec![0];
+        let mut col_idx = Vec::new();
+        let mut values = Vec::new();
+        for i in 0..dense.rows {
+            for j in 0..dense.cols {
+                if dense.data[i][j] != K::zero() {
+                    col_idx.push(j);
+                    values.push(dense.data[i][j].clone());
+                }
+            }
+            row_ptr.push(col_idx.len());
+        }
+        SparseMatrix { rows: dense.rows, cols: dense.cols, storage: SparseStorage::Csr { row_ptr, col_idx, values } }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        SparseMatrix {
+            rows: n,
+            cols: n,
+            storage: SparseStorage::Csr {
+                row_ptr: (0..=n).collect(),
+                col_idx: (0..n).collect(),
+                values: vec![K::one(); n],
+            },
+        }
+    }
+
+    /// Adds an entry to a COO matrix. Duplicate entries are summed when the
+    /// matrix is compressed, which is what finite-element assembly wants.
+    pub fn push(&mut self, row: usize, col: usize, val: K) {
+        assert!(row < self.rows && col < self.cols, "Index ({}, {}) out of bounds for {:?}", row, col, self.shape());
+        match &mut self.storage {
+            SparseStorage::Coo { row_idx, col_idx, values } => {
+                row_idx.push(row);
+                col_idx.push(col);
+                values.push(val);
+            }
+            _ => panic!("push is only available on COO matrices, call to_coo() first"),
+        }
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn format(&self) -> SparseFormat {
+        match self.storage {
+            SparseStorage::Coo { .. } => SparseFormat::Coo,
+            SparseStorage::Csr { .. } => SparseFormat::Csr,
+            SparseStorage::Csc { .. } => SparseFormat::Csc,
+        }
+    }
+
+    /// Stored entries, counting COO duplicates and explicit zeros.
+    pub fn nnz(&self) -> usize {
+        match &self.storage {
+            SparseStorage::Coo { values, .. }
+            | SparseStorage::Csr { values, .. }
+            | SparseStorage::Csc { values, .. } => values.len(),
+        }
+    }
+
+    pub fn triplets(&self) -> Vec<(usize, usize, K)> {
+        let mut out = Vec::with_capacity(self.nnz());
+        match &self.storage {
+            SparseStorage::Coo { row_idx, col_idx, values } => {
+                for k in 0..values.len() {
+                    out.push((row_idx[k], col_idx[k], values[k].clone()));
+                }
+            }
+            SparseStorage::Csr { row_ptr, col_idx, values } => {
+                for i in 0..self.rows {
+                    for k in row_ptr[i]..row_ptr[i + 1] {
+                        out.push((i, col_idx[k], values[k].clone()));
+                    }
+                }
+            }
+            SparseStorage::Csc { col_ptr, row_idx, values } => {
+                for j in 0..self.cols {
+                    for k in col_ptr[j]..col_ptr[j + 1] {
+                        out.push((row_idx[k], j, values[k].clone()));
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        match &self.storage {
+            SparseStorage::Csr { row_ptr, col_idx, values } => {
+                let range = row_ptr[row]..row_ptr[row + 1];
+                match col_idx[range.clone()].binary_search(&col) {
+                    Ok(k) => values[range.start + k].clone(),
+                    Err(_) => K::zero(),
+                }
+            }
+            SparseStorage::Csc { col_ptr, row_idx, values } => {
+                let range = col_ptr[col]..col_ptr[col + 1];
+                match row_idx[range.clone()].binary_search(&row) {
+                    Ok(k) => values[range.start + k].clone(),
+                    Err(_) => K::zero(),
+                }
+            }
+            SparseStorage::Coo { row_idx, col_idx, values } => {
+                let mut sum = K::zero();
+                for k in 0..values.len() {
+                    if row_idx[k] == row && col_idx[k] == col {
+                        sum += values[k].clone();
+                    }
+                }
+                sum
+            }
+        }
+    }
+
+    pub fn to_coo(&self) -> SparseMatrix<K> {
+        let triplets = self.triplets();
+        let mut row_idx = Vec::with_capacity(triplets.len());
+        let mut col_idx = Vec::with_capacity(triplets.len());
+        let mut values = Vec::with_capacity(triplets.len());
+        for (i, j, v) in triplets {
+            row_idx.push(i);
+            col_idx.push(j);
+            values.push(v);
+        }
+        SparseMatrix { rows: self.rows, cols: self.cols, storage: SparseStorage::Coo { row_idx, col_idx, values } }
+    }
+
+    pub fn to_csr(&self) -> SparseMatrix<K> {
+        if self.format() == SparseFormat::Csr {
+            return self.clone();
+        }
+        let (row_ptr, col_idx, values) = compress(self.rows, self.triplets());
+        SparseMatrix { rows: self.rows, cols: self.cols, storage: SparseStorage::Csr { row_ptr, col_idx, values } }
+    }
+
+    pub fn to_csc(&self) -> SparseMatrix<K> {
+        if self.format() == SparseFormat::Csc {
+            return self.clone();
+        }
+        let entries = self.triplets().into_iter().map(|(i, j, v)| (j, i, v)).collect();
+        let (col_ptr, row_idx, values) = compress(self.cols, entries);
+        SparseMatrix { rows: self.rows, cols: self.cols, storage: SparseStorage::Csc { col_ptr, row_idx, values } }
+    }
+
+    pub fn to_dense(&self) -> Matrix<K> {
+        let mut data = vec![vec![K::zero(); self.cols]; self.rows];
+        for (i, j, v) in self.triplets() {
+            data[i][j] += v;
+        }
+        Matrix { data, rows: self.rows, cols: self.cols }
+    }
+
+    /// Transposing swaps the roles of the index arrays, so CSR becomes CSC
+    /// (and vice versa) without moving any values.
+    pub fn transpose(&self) -> SparseMatrix<K> {
+        let storage = match &self.storage {
+            SparseStorage::Coo { row_idx, col_idx, values } => SparseStorage::Coo {
+                row_idx: col_idx.clone(),
+                col_idx: row_idx.clone(),
+                values: values.clone(),
+            },
+            SparseStorage::Csr { row_ptr, col_idx, values } => SparseStorage::Csc {
+                col_ptr: row_ptr.clone(),
+                row_idx: col_idx.clone(),
+                values: values.clone(),
+            },
+            SparseStorage::Csc { col_ptr, row_idx, values } => SparseStorage::Csr {
+                row_ptr: col_ptr.clone(),
+                col_idx: row_idx.clone(),
+                values: values.clone(),
+            },
+        };
+        SparseMatrix { rows: self.cols, cols: self.rows, storage }
+    }
+
+    pub fn scl(&mut self, a: K) {
+        match &mut self.storage {
+            SparseStorage::Coo { values, .. }
+            | SparseStorage::Csr { values, .. }
+            | SparseStorage::Csc { values, .. } => {
+                for v in values.iter_mut() {
+                    *v *= a.clone();
+                }
+            }
+        }
+    }
+
+    pub fn mul_vec(&self, vec: Vector<K>) -> Vector<K> {
+        assert_eq!(self.cols, vec.size(), "Error: The number of columns in A must equal the size of the vector!");
+        let mut result = vec![K::zero(); self.rows];
+        match &self.storage {
+            SparseStorage::Csr { row_ptr, col_idx, values } => {
+                for i in 0..self.rows {
+                    let mut sum = K::zero();
+                    for k in row_ptr[i]..row_ptr[i + 1] {
+                        sum += values[k].clone() * vec.data[col_idx[k]].clone();
+                    }
+                    result[i] = sum;
+                }
+            }
+            SparseStorage::Csc { col_ptr, row_idx, values } => {
+                for j in 0..self.cols {
+                    let x = vec.data[j].clone();
+                    for k in col_ptr[j]..col_ptr[j + 1] {
+                        result[row_idx[k]] += values[k].clone() * x.clone();
+                    }
+                }
+            }
+            SparseStorage::Coo { row_idx, col_idx, values } => {
+                for k in 0..values.len() {
+                    result[row_idx[k]] += values[k].clone() * vec.data[col_idx[k]].clone();
+                }
+            }
+        }
+        Vector::from(result)
+    }
+
+    /// Sparse x dense product.
+    #[allow(clippy::needless_range_loop)]
+    pub fn mul_mat(&self, other: Matrix<K>) -> Matrix<K> {
+        assert_eq!(self.cols, other.rows, "Error: The number of columns in A must equal the number of rows in B!");
+        let mut result = vec![vec![K::zero(); other.cols]; self.rows];
+        for (i, k, v) in self.triplets() {
+            for j in 0..other.cols {
+                result[i][j] += v.clone() * other.data[k][j].clone();
+            }
+        }
+        Matrix { data: result, rows: self.rows, cols: other.cols }
+    }
+
+    /// Sparse x sparse product (Gustavson's row-by-row algorithm), returned in CSR.
+    pub fn mul_sparse(&self, other: &SparseMatrix<K>) -> SparseMatrix<K> {
+        assert_eq!(self.cols, other.rows, "Error: The number of columns in A must equal the number of rows in B!");
+        let a = self.to_csr();
+        let b = other.to_csr();
+        let (a_ptr, a_idx, a_val) = a.csr_parts();
+        let (b_ptr, b_idx, b_val) = b.csr_parts();
+
+        let mut acc = vec![K::zero(); other.cols];
+        let mut marker = vec![usize::MAX; other.cols];
+        let mut row_ptr = vec![0];
+        let mut col_idx = Vec::new();
+        let mut values = Vec::new();
+
+        for i in 0..self.rows {
+            let start = col_idx.len();
+            for ka in a_ptr[i]..a_ptr[i + 1] {
+                let k = a_idx[ka];
+                for kb in b_ptr[k]..b_ptr[k + 1] {
+                    let j = b_idx[kb];
+                    if marker[j] != i {
+                        marker[j] = i;
+                        acc[j] = K::zero();
+                        col_idx.push(j);
+                    }
+                    acc[j] += a_val[ka].clone() * b_val[kb].clone();
+                }
+            }
+            col_idx[start..].sort_unstable();
+            for k in start..col_idx.len() {
+                values.push(acc[col_idx[k]].clone());
+            }
+            row_ptr.push(col_idx.len());
+        }
+
+        SparseMatrix { rows: self.rows, cols: other.cols, storage: SparseStorage::Csr { row_ptr, col_idx, values } }
+    }
+
+    pub(crate) fn csr_parts(&self) -> (&[usize], &[usize], &[K]) {
+        match &self.storage {
+            SparseStorage::Csr { row_ptr, col_idx, values } => (row_ptr, col_idx, values),
+            _ => panic!("Expected a CSR matrix"),
+        }
+    }
+}
+
+// Sorts (major, minor, value) entries, sums duplicates and builds the offset array.
+fn compress<K: Field>(n_major: usize, mut entries: Vec<(usize, usize, K)>) -> (Vec<usize>, Vec<usize>, Vec<K>) {
+    entries.sort_by_key(|&(major, minor, _)| (major, minor));
+
+    let mut ptr = vec![0; n_major + 1];
+    let mut idx: Vec<usize> = Vec::with_capacity(entries.len());
+    let mut values: Vec<K> = Vec::with_capacity(entries.len());
+    let mut last: Option<(usize, usize)> = None;
+
+    for (major, minor, v) in entries {
+        if last == Some((major, minor)) {
+            let k = values.len() - 1;
+            values[k] += v;
+        } else {
+            idx.push(minor);
+            values.push(v);
+            ptr[major + 1] += 1;
+            last = Some((major, minor));
+        }
+    }
+    for i in 0..n_major {
+        ptr[i + 1] += ptr[i];
+    }
+    (ptr, idx, values)
+}
+
+impl<K: Field> LinearOperator<K> for SparseMatrix<K> {
+    fn size(&self) -> usize {
+        assert!(self.is_square(), "LinearOperator requires a square matrix, got {:?}", self.shape());
+        self.rows
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x.clone())
+    }
+}
+
+impl<K: Field> fmt::Display for SparseMatrix<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, j, v) in self.triplets() {
+            writeln!(f, "({}, {}) {}", i, j, v)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+pub mod matrix;
+pub mod ordering;
+pub mod cholesky;
+pub mod lu;
+
+pub use matrix::SparseMatrix;
+pub use matrix::SparseFormat;
+pub use matrix::SparseStorage;
+pub use ordering::FillOrdering;
+pub use ordering::fill_ordering;
+pub use cholesky::SymbolicCholesky;
+pub use cholesky::SparseCholesky;
+pub use lu::SymbolicLu;
+pub use lu::SparseLu;
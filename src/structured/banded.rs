@@ -0,0 +1,153 @@
+use crate::types::Field;
+use crate::{Matrix, Vector};
+use crate::solvers::LinearOperator;
+
+/// Square matrix with `kl` sub- and `ku` super-diagonals. Row i stores the
+/// window of columns i - kl ..= i + ku, so entry (i, j) lives at data[i][j + kl - i].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Banded<K: Field> {
+    pub n: usize,
+    pub kl: usize,
+    pub ku: usize,
+    pub data: Vec<Vec<K>>,
+}
+
+impl<K: Field> Banded<K> {
+    pub fn new(n: usize, kl: usize, ku: usize) -> Self {
+        Self { n, kl, ku, data: vec![vec![K::zero(); kl + ku + 1]; n] }
+    }
+
+    pub fn from_dense(dense: &Matrix<K>, kl: usize, ku: usize) -> Self {
+        assert!(dense.is_square(), "Banded requires a square matrix, got {:?}", dense.shape());
+        let mut band = Self::new(dense.rows, kl, ku);
+        for i in 0..dense.rows {
+            for j in 0..dense.cols {
+                if band.in_band(i, j) {
+                    band.set(i, j, dense.data[i][j].clone());
+                } else {
+                    assert!(dense.data[i][j] == K::zero(), "Entry ({}, {}) lies outside the band", i, j);
+                }
+            }
+        }
+        band
+    }
+
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    pub fn in_band(&self, row: usize, col: usize) -> bool {
+        col + self.kl >= row && col <= row + self.ku
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        if self.in_band(row, col) { self.data[row][col + self.kl - row].clone() } else { K::zero() }
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, val: K) {
+        assert!(self.in_band(row, col), "Entry ({}, {}) lies outside the band", row, col);
+        self.data[row][col + self.kl - row] = val;
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn mul_vec(&self, vec: Vector<K>) -> Vector<K> {
+        assert_eq!(self.n, vec.size(), "Vector size mismatch: {} vs {}", self.n, vec.size());
+        let mut result = vec![K::zero(); self.n];
+        for i in 0..self.n {
+            let mut sum = K::zero();
+            for j in i.saturating_sub(self.kl)..(i + self.ku + 1).min(self.n) {
+                sum += self.get(i, j) * vec.data[j].clone();
+            }
+            result[i] = sum;
+        }
+        Vector::from(result)
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn to_dense(&self) -> Matrix<K> {
+        let mut data = vec![vec![K::zero(); self.n]; self.n];
+        for i in 0..self.n {
+            for j in i.saturating_sub(self.kl)..(i + self.ku + 1).min(self.n) {
+                data[i][j] = self.get(i, j);
+            }
+        }
+        Matrix::from(data)
+    }
+}
+
+impl<K: Field + PartialOrd> Banded<K> {
+    /// Banded LU with partial pivoting, O(n kl (kl + ku)). Row swaps can push
+    /// fill up to kl + ku above the diagonal, so U is held with that bandwidth.
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, String> {
+        let n = self.n;
+        assert_eq!(n, b.size(), "Right-hand side size mismatch: {} vs {}", n, b.size());
+        let kl = self.kl;
+        let width = kl + kl + self.ku + 1;
+        let upper = kl + self.ku;
+
+        // w[i][j + kl - i] holds entry (i, j) for j in i - kl ..= i + kl + ku.
+        let mut w = vec![vec![K::zero(); width]; n];
+        for i in 0..n {
+            for j in i.saturating_sub(kl)..(i + self.ku + 1).min(n) {
+                w[i][j + kl - i] = self.get(i, j);
+            }
+        }
+        let mut x = b.data.clone();
+
+        for k in 0..n {
+            let last = (k + kl).min(n - 1);
+            let mut max_row = k;
+            for row in k + 1..=last {
+                if w[row][k + kl - row].clone().abs() > w[max_row][k + kl - max_row].clone().abs() {
+                    max_row = row;
+                }
+            }
+            if w[max_row][k + kl - max_row] == K::zero() {
+                return Err("Matrix is singular".to_string());
+            }
+
+            let end = (k + upper + 1).min(n);
+            if max_row != k {
+                for j in k..end {
+                    let temp = w[k][j + kl - k].clone();
+                    w[k][j + kl - k] = w[max_row][j + kl - max_row].clone();
+                    w[max_row][j + kl - max_row] = temp;
+                }
+                x.swap(k, max_row);
+            }
+
+            let pivot = w[k][kl].clone();
+            for row in k + 1..=last {
+                let factor = w[row][k + kl - row].clone() / pivot.clone();
+                if factor == K::zero() {
+                    continue;
+                }
+                for j in k..end {
+                    let u_kj = w[k][j + kl - k].clone();
+                    w[row][j + kl - row] -= factor.clone() * u_kj;
+                }
+                let x_k = x[k].clone();
+                x[row] -= factor * x_k;
+            }
+        }
+
+        for i in (0..n).rev() {
+            let mut sum = x[i].clone();
+            for j in i + 1..(i + upper + 1).min(n) {
+                sum -= w[i][j + kl - i].clone() * x[j].clone();
+            }
+            x[i] = sum / w[i][kl].clone();
+        }
+        Ok(Vector::from(x))
+    }
+}
+
+impl<K: Field> LinearOperator<K> for Banded<K> {
+    fn size(&self) -> usize {
+        self.n
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x.clone())
+    }
+}
@@ -0,0 +1,75 @@
+use crate::types::Field;
+use crate::{Matrix, Vector};
+use crate::solvers::LinearOperator;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagonal<K: Field> {
+    pub diag: Vec<K>,
+}
+
+impl<K: Field> Diagonal<K> {
+    pub fn from(diag: impl Into<Vec<K>>) -> Self {
+        Self { diag: diag.into() }
+    }
+
+    pub fn from_dense(dense: &Matrix<K>) -> Self {
+        assert!(dense.is_square(), "Diagonal requires a square matrix, got {:?}", dense.shape());
+        Self { diag: (0..dense.rows).map(|i| dense.data[i][i].clone()).collect() }
+    }
+
+    pub fn size(&self) -> usize {
+        self.diag.len()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        if row == col { self.diag[row].clone() } else { K::zero() }
+    }
+
+    pub fn mul_vec(&self, vec: Vector<K>) -> Vector<K> {
+        vec.assert_same_size(&Vector::from(self.diag.clone()));
+        Vector::from((0..self.size()).map(|i| self.diag[i].clone() * vec.data[i].clone()).collect::<Vec<K>>())
+    }
+
+    pub fn determinant(&self) -> K {
+        let mut det = K::one();
+        for d in &self.diag {
+            det *= d.clone();
+        }
+        det
+    }
+
+    pub fn inverse(&self) -> Result<Diagonal<K>, String> {
+        let mut inv = Vec::with_capacity(self.size());
+        for d in &self.diag {
+            if *d == K::zero() {
+                return Err("Matrix is singular".to_string());
+            }
+            inv.push(K::one() / d.clone());
+        }
+        Ok(Diagonal { diag: inv })
+    }
+
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, String> {
+        Ok(self.inverse()?.mul_vec(b.clone()))
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn to_dense(&self) -> Matrix<K> {
+        let n = self.size();
+        let mut data = vec![vec![K::zero(); n]; n];
+        for i in 0..n {
+            data[i][i] = self.diag[i].clone();
+        }
+        Matrix::from(data)
+    }
+}
+
+impl<K: Field> LinearOperator<K> for Diagonal<K> {
+    fn size(&self) -> usize {
+        self.diag.len()
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x.clone())
+    }
+}
@@ -0,0 +1,12 @@
+pub mod diagonal;
+pub mod tridiagonal;
+pub mod banded;
+pub mod triangular;
+pub mod symmetric;
+
+pub use diagonal::Diagonal;
+pub use tridiagonal::Tridiagonal;
+pub use banded::Banded;
+pub use triangular::UpperTriangular;
+pub use triangular::LowerTriangular;
+pub use symmetric::Symmetric;
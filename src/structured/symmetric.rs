@@ -0,0 +1,121 @@
+use crate::types::Field;
+use crate::{Matrix, Vector};
+use crate::solvers::LinearOperator;
+use super::triangular::LowerTriangular;
+
+/// Symmetric matrix holding only its lower triangle, packed by rows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symmetric<K: Field> {
+    pub lower: LowerTriangular<K>,
+}
+
+impl<K: Field> Symmetric<K> {
+    pub fn new(n: usize) -> Self {
+        Self { lower: LowerTriangular::new(n) }
+    }
+
+    /// Reads the lower triangle and checks that it mirrors the upper one.
+    pub fn from_dense(dense: &Matrix<K>) -> Result<Self, String> {
+        if !dense.is_square() {
+            return Err("Matrix must be square".to_string());
+        }
+        for i in 0..dense.rows {
+            for j in 0..i {
+                if dense.data[i][j] != dense.data[j][i] {
+                    return Err(format!("Matrix is not symmetric at ({}, {})", i, j));
+                }
+            }
+        }
+        Ok(Self { lower: LowerTriangular::from_dense(dense) })
+    }
+
+    pub fn size(&self) -> usize {
+        self.lower.n
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        if col <= row { self.lower.get(row, col) } else { self.lower.get(col, row) }
+    }
+
+    /// Sets both (row, col) and (col, row).
+    pub fn set(&mut self, row: usize, col: usize, val: K) {
+        if col <= row { self.lower.set(row, col, val) } else { self.lower.set(col, row, val) }
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn mul_vec(&self, vec: Vector<K>) -> Vector<K> {
+        let n = self.size();
+        assert_eq!(n, vec.size(), "Vector size mismatch: {} vs {}", n, vec.size());
+        let mut result = vec![K::zero(); n];
+        for i in 0..n {
+            let mut sum = K::zero();
+            for j in 0..n {
+                sum += self.get(i, j) * vec.data[j].clone();
+            }
+            result[i] = sum;
+        }
+        Vector::from(result)
+    }
+
+    /// A = L D L^T with L unit lower triangular, computed without pivoting.
+    /// Works for positive definite and many indefinite matrices; fails on a
+    /// zero leading principal minor.
+    #[allow(clippy::needless_range_loop)]
+    pub fn ldl(&self) -> Result<(LowerTriangular<K>, Vec<K>), String> {
+        let n = self.size();
+        let mut l = LowerTriangular::new(n);
+        let mut d = vec![K::zero(); n];
+        for j in 0..n {
+            let mut dj = self.get(j, j);
+            for k in 0..j {
+                let l_jk: K = l.get(j, k);
+                dj -= l_jk.clone() * l_jk * d[k].clone();
+            }
+            if dj == K::zero() {
+                return Err(format!("Zero pivot at row {}", j));
+            }
+            d[j] = dj.clone();
+            l.set(j, j, K::one());
+            for i in j + 1..n {
+                let mut sum = self.get(i, j);
+                for k in 0..j {
+                    sum -= l.get(i, k) * l.get(j, k) * d[k].clone();
+                }
+                l.set(i, j, sum / dj.clone());
+            }
+        }
+        Ok((l, d))
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, String> {
+        let (l, d) = self.ldl()?;
+        let mut y = l.solve(b)?;
+        for i in 0..y.data.len() {
+            y.data[i] = y.data[i].clone() / d[i].clone();
+        }
+        l.transpose().solve(&y)
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn to_dense(&self) -> Matrix<K> {
+        let n = self.size();
+        let mut data = vec![vec![K::zero(); n]; n];
+        for i in 0..n {
+            for j in 0..n {
+                data[i][j] = self.get(i, j);
+            }
+        }
+        Matrix::from(data)
+    }
+}
+
+impl<K: Field> LinearOperator<K> for Symmetric<K> {
+    fn size(&self) -> usize {
+        self.lower.n
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x.clone())
+    }
+}
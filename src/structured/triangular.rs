@@ -0,0 +1,229 @@
+use crate::types::Field;
+use crate::{Matrix, Vector};
+use crate::solvers::LinearOperator;
+
+/// Packed by rows: row i stores columns i..n, n(n + 1) / 2 entries in total.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpperTriangular<K: Field> {
+    pub n: usize,
+    pub data: Vec<K>,
+}
+
+/// Packed by rows: row i stores columns 0..=i, n(n + 1) / 2 entries in total.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LowerTriangular<K: Field> {
+    pub n: usize,
+    pub data: Vec<K>,
+}
+
+impl<K: Field> UpperTriangular<K> {
+    pub fn new(n: usize) -> Self {
+        Self { n, data: vec![K::zero(); n * (n + 1) / 2] }
+    }
+
+    /// Copies the upper triangle; anything below the diagonal is ignored.
+    pub fn from_dense(dense: &Matrix<K>) -> Self {
+        assert!(dense.is_square(), "UpperTriangular requires a square matrix, got {:?}", dense.shape());
+        let mut data = Vec::with_capacity(dense.rows * (dense.rows + 1) / 2);
+        for i in 0..dense.rows {
+            data.extend_from_slice(&dense.data[i][i..]);
+        }
+        Self { n: dense.rows, data }
+    }
+
+    fn index(&self, row: usize, col: usize) -> usize {
+        row * self.n - row * (row + 1) / 2 + col
+    }
+
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        if col >= row { self.data[self.index(row, col)].clone() } else { K::zero() }
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, val: K) {
+        assert!(col >= row, "Entry ({}, {}) lies below the diagonal", row, col);
+        let k = self.index(row, col);
+        self.data[k] = val;
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn mul_vec(&self, vec: Vector<K>) -> Vector<K> {
+        assert_eq!(self.n, vec.size(), "Vector size mismatch: {} vs {}", self.n, vec.size());
+        let mut result = vec![K::zero(); self.n];
+        for i in 0..self.n {
+            let mut sum = K::zero();
+            for j in i..self.n {
+                sum += self.get(i, j) * vec.data[j].clone();
+            }
+            result[i] = sum;
+        }
+        Vector::from(result)
+    }
+
+    pub fn determinant(&self) -> K {
+        let mut det = K::one();
+        for i in 0..self.n {
+            det *= self.get(i, i);
+        }
+        det
+    }
+
+    /// Back substitution, O(n^2).
+    #[allow(clippy::needless_range_loop)]
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, String> {
+        assert_eq!(self.n, b.size(), "Right-hand side size mismatch: {} vs {}", self.n, b.size());
+        let mut x = b.data.clone();
+        for i in (0..self.n).rev() {
+            let pivot = self.get(i, i);
+            if pivot == K::zero() {
+                return Err("Matrix is singular".to_string());
+            }
+            let mut sum = x[i].clone();
+            for j in i + 1..self.n {
+                sum -= self.get(i, j) * x[j].clone();
+            }
+            x[i] = sum / pivot;
+        }
+        Ok(Vector::from(x))
+    }
+
+    pub fn transpose(&self) -> LowerTriangular<K> {
+        let mut lower = LowerTriangular::new(self.n);
+        for i in 0..self.n {
+            for j in i..self.n {
+                lower.set(j, i, self.get(i, j));
+            }
+        }
+        lower
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn to_dense(&self) -> Matrix<K> {
+        let mut data = vec![vec![K::zero(); self.n]; self.n];
+        for i in 0..self.n {
+            for j in i..self.n {
+                data[i][j] = self.get(i, j);
+            }
+        }
+        Matrix::from(data)
+    }
+}
+
+impl<K: Field> LowerTriangular<K> {
+    pub fn new(n: usize) -> Self {
+        Self { n, data: vec![K::zero(); n * (n + 1) / 2] }
+    }
+
+    /// Copies the lower triangle; anything above the diagonal is ignored.
+    pub fn from_dense(dense: &Matrix<K>) -> Self {
+        assert!(dense.is_square(), "LowerTriangular requires a square matrix, got {:?}", dense.shape());
+        let mut data = Vec::with_capacity(dense.rows * (dense.rows + 1) / 2);
+        for i in 0..dense.rows {
+            data.extend_from_slice(&dense.data[i][..=i]);
+        }
+        Self { n: dense.rows, data }
+    }
+
+    fn index(&self, row: usize, col: usize) -> usize {
+        row * (row + 1) / 2 + col
+    }
+
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        if col <= row { self.data[self.index(row, col)].clone() } else { K::zero() }
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, val: K) {
+        assert!(col <= row, "Entry ({}, {}) lies above the diagonal", row, col);
+        let k = self.index(row, col);
+        self.data[k] = val;
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn mul_vec(&self, vec: Vector<K>) -> Vector<K> {
+        assert_eq!(self.n, vec.size(), "Vector size mismatch: {} vs {}", self.n, vec.size());
+        let mut result = vec![K::zero(); self.n];
+        for i in 0..self.n {
+            let mut sum = K::zero();
+            for j in 0..=i {
+                sum += self.get(i, j) * vec.data[j].clone();
+            }
+            result[i] = sum;
+        }
+        Vector::from(result)
+    }
+
+    pub fn determinant(&self) -> K {
+        let mut det = K::one();
+        for i in 0..self.n {
+            det *= self.get(i, i);
+        }
+        det
+    }
+
+    /// Forward substitution, O(n^2).
+    #[allow(clippy::needless_range_loop)]
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, String> {
+        assert_eq!(self.n, b.size(), "Right-hand side size mismatch: {} vs {}", self.n, b.size());
+        let mut x = b.data.clone();
+        for i in 0..self.n {
+            let pivot = self.get(i, i);
+            if pivot == K::zero() {
+                return Err("Matrix is singular".to_string());
+            }
+            let mut sum = x[i].clone();
+            for j in 0..i {
+                sum -= self.get(i, j) * x[j].clone();
+            }
+            x[i] = sum / pivot;
+        }
+        Ok(Vector::from(x))
+    }
+
+    pub fn transpose(&self) -> UpperTriangular<K> {
+        let mut upper = UpperTriangular::new(self.n);
+        for i in 0..self.n {
+            for j in 0..=i {
+                upper.set(j, i, self.get(i, j));
+            }
+        }
+        upper
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn to_dense(&self) -> Matrix<K> {
+        let mut data = vec![vec![K::zero(); self.n]; self.n];
+        for i in 0..self.n {
+            for j in 0..=i {
+                data[i][j] = self.get(i, j);
+            }
+        }
+        Matrix::from(data)
+    }
+}
+
+impl<K: Field> LinearOperator<K> for UpperTriangular<K> {
+    fn size(&self) -> usize {
+        self.n
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x.clone())
+    }
+}
+
+impl<K: Field> LinearOperator<K> for LowerTriangular<K> {
+    fn size(&self) -> usize {
+        self.n
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x.clone())
+    }
+}
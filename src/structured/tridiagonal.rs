@@ -0,0 +1,136 @@
+use crate::types::Field;
+use crate::{Matrix, Vector};
+use crate::solvers::LinearOperator;
+
+/// `lower[i]` is entry (i + 1, i), `upper[i]` is entry (i, i + 1).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tridiagonal<K: Field> {
+    pub lower: Vec<K>,
+    pub diag: Vec<K>,
+    pub upper: Vec<K>,
+}
+
+impl<K: Field> Tridiagonal<K> {
+    pub fn from(lower: impl Into<Vec<K>>, diag: impl Into<Vec<K>>, upper: impl Into<Vec<K>>) -> Self {
+        let (lower, diag, upper) = (lower.into(), diag.into(), upper.into());
+        let off = diag.len().saturating_sub(1);
+        assert!(lower.len() == off && upper.len() == off,
+            "Tridiagonal needs {} off-diagonal entries, got {} and {}", off, lower.len(), upper.len());
+        Self { lower, diag, upper }
+    }
+
+    pub fn from_dense(dense: &Matrix<K>) -> Self {
+        assert!(dense.is_square(), "Tridiagonal requires a square matrix, got {:?}", dense.shape());
+        let n = dense.rows;
+        let lower = (1..n).map(|i| dense.data[i][i - 1].clone()).collect::<Vec<K>>();
+        let diag = (0..n).map(|i| dense.data[i][i].clone()).collect::<Vec<K>>();
+        let upper = (1..n).map(|i| dense.data[i - 1][i].clone()).collect::<Vec<K>>();
+        Self { lower, diag, upper }
+    }
+
+    pub fn size(&self) -> usize {
+        self.diag.len()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        if row == col {
+            self.diag[row].clone()
+        } else if row == col + 1 {
+            self.lower[col].clone()
+        } else if col == row + 1 {
+            self.upper[row].clone()
+        } else {
+            K::zero()
+        }
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn mul_vec(&self, vec: Vector<K>) -> Vector<K> {
+        let n = self.size();
+        assert_eq!(n, vec.size(), "Vector size mismatch: {} vs {}", n, vec.size());
+        let mut result = vec![K::zero(); n];
+        for i in 0..n {
+            let mut sum = self.diag[i].clone() * vec.data[i].clone();
+            if i > 0 {
+                sum += self.lower[i - 1].clone() * vec.data[i - 1].clone();
+            }
+            if i + 1 < n {
+                sum += self.upper[i].clone() * vec.data[i + 1].clone();
+            }
+            result[i] = sum;
+        }
+        Vector::from(result)
+    }
+
+    /// Thomas algorithm: Gaussian elimination without pivoting, O(n). Stable for
+    /// diagonally dominant or symmetric positive definite matrices; otherwise
+    /// use `Banded` with kl = ku = 1, which pivots.
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, String> {
+        let n = self.size();
+        assert_eq!(n, b.size(), "Right-hand side size mismatch: {} vs {}", n, b.size());
+        if n == 0 {
+            return Ok(Vector::from(Vec::new()));
+        }
+
+        let mut c = vec![K::zero(); n];
+        let mut d = vec![K::zero(); n];
+        let mut denom = self.diag[0].clone();
+        for i in 0..n {
+            if i > 0 {
+                denom = self.diag[i].clone() - self.lower[i - 1].clone() * c[i - 1].clone();
+            }
+            if denom == K::zero() {
+                return Err(format!("Zero pivot at row {}", i));
+            }
+            if i + 1 < n {
+                c[i] = self.upper[i].clone() / denom.clone();
+            }
+            d[i] = if i > 0 { (b.data[i].clone() - self.lower[i - 1].clone() * d[i - 1].clone()) / denom.clone() } else { b.data[0].clone() / denom.clone() };
+        }
+
+        for i in (0..n - 1).rev() {
+            let next = d[i + 1].clone();
+            d[i] -= c[i].clone() * next;
+        }
+        Ok(Vector::from(d))
+    }
+
+    pub fn determinant(&self) -> K {
+        // Three-term recurrence for the leading principal minors.
+        let mut prev = K::one();
+        let mut det = K::one();
+        for i in 0..self.size() {
+            let next = if i == 0 {
+                self.diag[0].clone()
+            } else {
+                self.diag[i].clone() * det.clone() - self.lower[i - 1].clone() * self.upper[i - 1].clone() * prev
+            };
+            prev = det;
+            det = next;
+        }
+        det
+    }
+
+    pub fn to_dense(&self) -> Matrix<K> {
+        let n = self.size();
+        let mut data = vec![vec![K::zero(); n]; n];
+        for i in 0..n {
+            data[i][i] = self.diag[i].clone();
+            if i + 1 < n {
+                data[i + 1][i] = self.lower[i].clone();
+                data[i][i + 1] = self.upper[i].clone();
+            }
+        }
+        Matrix::from(data)
+    }
+}
+
+impl<K: Field> LinearOperator<K> for Tridiagonal<K> {
+    fn size(&self) -> usize {
+        self.diag.len()
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x.clone())
+    }
+}
@@ -0,0 +1,190 @@
+use std::fmt;
+use std::cmp::Ordering;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign};
+use super::field::{Semiring, Ring, Field};
+use super::bigint::BigInt;
+use super::rational::Rational;
+use crate::Matrix;
+
+/// Exact fraction of two `BigInt`s, kept in lowest terms with a positive
+/// denominator. Same contract as `Rational`, without the overflow.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BigRational {
+    num: BigInt,
+    den: BigInt,
+}
+
+impl BigRational {
+    pub fn new(num: BigInt, den: BigInt) -> Self {
+        assert!(!den.is_zero(), "BigRational: zero denominator");
+        let g = num.gcd(&den);
+        let (mut num, mut den) = (&num / &g, &den / &g);
+        if den.is_negative() {
+            num = -num;
+            den = -den;
+        }
+        Self { num, den }
+    }
+
+    pub fn numer(&self) -> BigInt {
+        self.num.clone()
+    }
+
+    pub fn denom(&self) -> BigInt {
+        self.den.clone()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == BigInt::one()
+    }
+
+    pub fn recip(self) -> Self {
+        Self::new(self.den, self.num)
+    }
+}
+
+impl Default for BigRational {
+    fn default() -> Self {
+        Self { num: BigInt::zero(), den: BigInt::one() }
+    }
+}
+
+impl From<BigInt> for BigRational {
+    fn from(n: BigInt) -> Self {
+        Self { num: n, den: BigInt::one() }
+    }
+}
+
+impl From<i64> for BigRational {
+    fn from(n: i64) -> Self {
+        Self::from(BigInt::from(n))
+    }
+}
+
+impl From<Rational> for BigRational {
+    fn from(r: Rational) -> Self {
+        Self { num: BigInt::from(r.numer()), den: BigInt::from(r.denom()) }
+    }
+}
+
+impl From<f32> for BigRational {
+    fn from(x: f32) -> Self {
+        Self::from(Rational::from_f32(x))
+    }
+}
+
+impl From<BigRational> for f32 {
+    fn from(r: BigRational) -> f32 {
+        // Shift both sides down first so huge numerators and denominators
+        // do not turn into inf / inf.
+        let shift = r.num.bits().max(r.den.bits()).saturating_sub(1000) as u32;
+        let scale = BigInt::from(2).pow(shift);
+        ((&r.num / &scale).to_f64() / (&r.den / &scale).to_f64()) as f32
+    }
+}
+
+impl Add for BigRational {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(&self.num * &rhs.den + &rhs.num * &self.den, &self.den * &rhs.den)
+    }
+}
+
+impl Sub for BigRational {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for BigRational {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(&self.num * &rhs.num, &self.den * &rhs.den)
+    }
+}
+
+impl Div for BigRational {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        assert!(!rhs.num.is_zero(), "BigRational division by zero");
+        Self::new(&self.num * &rhs.den, &self.den * &rhs.num)
+    }
+}
+
+impl Neg for BigRational {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self { num: -self.num, den: self.den }
+    }
+}
+
+impl AddAssign for BigRational {
+    fn add_assign(&mut self, rhs: Self) { *self = std::mem::take(self) + rhs; }
+}
+impl SubAssign for BigRational {
+    fn sub_assign(&mut self, rhs: Self) { *self = std::mem::take(self) - rhs; }
+}
+impl MulAssign for BigRational {
+    fn mul_assign(&mut self, rhs: Self) { *self = std::mem::take(self) * rhs; }
+}
+
+impl PartialOrd for BigRational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigRational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.num * &other.den - &other.num * &self.den).signum().cmp(&0)
+    }
+}
+
+impl fmt::Display for BigRational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl Semiring for BigRational {
+    fn zero() -> Self { Self::default() }
+    fn one() -> Self { Self::from(BigInt::one()) }
+}
+
+impl Ring for BigRational {}
+
+impl Field for BigRational {
+    fn fma(self, b: Self, c: Self) -> Self { self * b + c }
+    fn abs(self) -> Self {
+        Self { num: self.num.abs(), den: self.den }
+    }
+    // Exact for squares of rationals. Otherwise num and den are both scaled by
+    // 4^64 before their integer square roots, so each floor is off by less
+    // than 2^-64 of its value and the quotient is good to about 64 bits at
+    // any magnitude. Panics on negative input, which has no real root.
+    fn sqrt(self) -> Self {
+        assert!(!self.num.is_negative(), "Error: Cannot take the square root of the negative rational {}!", self);
+        let (n, d) = (self.num.sqrt(), self.den.sqrt());
+        if &n * &n == self.num && &d * &d == self.den {
+            Self { num: n, den: d }
+        } else {
+            let scale = BigInt::from(4).pow(64);
+            Self::new((&self.num * &scale).sqrt(), (&self.den * &scale).sqrt())
+        }
+    }
+}
+
+impl Matrix<f32> {
+    pub fn to_big_rational(&self) -> Matrix<BigRational> {
+        Matrix::from(
+            self.data.iter()
+                .map(|row| row.iter().map(|&v| BigRational::from(v)).collect::<Vec<BigRational>>())
+                .collect::<Vec<Vec<BigRational>>>()
+        )
+    }
+}
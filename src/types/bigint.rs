@@ -0,0 +1,442 @@
+use std::fmt;
+use std::cmp::Ordering;
+use std::str::FromStr;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign};
+use super::field::{Semiring, Ring};
+use crate::Matrix;
+
+const BASE: u64 = 1 << 32;
+
+/// Arbitrary-precision signed integer: a sign and a heap-allocated magnitude
+/// of 32-bit limbs, least significant first and without trailing zero limbs,
+/// so results never overflow.
+///
+/// `BigInt` is a `Ring` but not a `Field`, as its `/` truncates toward zero
+/// and elimination over it would be silently wrong. `Matrix<BigInt>` gets
+/// `determinant` by Bareiss elimination, whose divisions are all exact; use
+/// `BigRational` for inverses and solves.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    neg: bool,
+    mag: Vec<u32>,
+}
+
+impl BigInt {
+    pub fn is_zero(&self) -> bool {
+        self.mag.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.neg
+    }
+
+    pub fn signum(&self) -> i32 {
+        if self.is_zero() { 0 } else if self.neg { -1 } else { 1 }
+    }
+
+    /// Number of significant bits of |self|.
+    pub fn bits(&self) -> usize {
+        match self.mag.last() {
+            Some(top) => 32 * self.mag.len() - top.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let (mut base, mut result) = (self.clone(), Self::one());
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.abs(), other.abs());
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    /// Quotient truncated toward zero and the remainder, which has the sign of `self`.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "Error: BigInt division by zero!");
+        let (q, r) = mag_div_rem(&self.mag, &rhs.mag);
+        (Self::signed(self.neg != rhs.neg, q), Self::signed(self.neg, r))
+    }
+
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        if rhs.is_zero() { None } else { Some(self.div_rem(rhs).0) }
+    }
+
+    pub fn abs(&self) -> Self {
+        Self { neg: false, mag: self.mag.clone() }
+    }
+
+    /// Floor of the square root, by Newton's iteration from above.
+    pub fn sqrt(&self) -> Self {
+        assert!(!self.neg, "Error: Cannot take the square root of the negative integer {}!", self);
+        if self.is_zero() {
+            return Self::zero();
+        }
+        let mut x = Self::power_of_two(self.bits() / 2 + 1);
+        loop {
+            let y = (&x + &(self / &x)).half();
+            if mag_cmp(&y.mag, &x.mag) != Ordering::Less {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    /// Nearest f64, or an infinity past f64's range.
+    pub fn to_f64(&self) -> f64 {
+        let magnitude = self.mag.iter().rev().fold(0.0, |acc, &d| acc * BASE as f64 + d as f64);
+        if self.neg { -magnitude } else { magnitude }
+    }
+
+    fn signed(neg: bool, mut mag: Vec<u32>) -> Self {
+        while mag.last() == Some(&0) {
+            mag.pop();
+        }
+        Self { neg: neg && !mag.is_empty(), mag }
+    }
+
+    fn power_of_two(k: usize) -> Self {
+        let mut mag = vec![0; k / 32 + 1];
+        mag[k / 32] = 1 << (k % 32);
+        Self { neg: false, mag }
+    }
+
+    // |self| / 2 with the sign kept, for a non-negative self.
+    fn half(mut self) -> Self {
+        let mut carry = 0;
+        for d in self.mag.iter_mut().rev() {
+            let next = *d & 1;
+            *d = (*d >> 1) | (carry << 31);
+            carry = next;
+        }
+        Self::signed(self.neg, self.mag)
+    }
+
+    fn mul_small_add(&mut self, factor: u32, addend: u32) {
+        let mut carry = addend as u64;
+        for d in self.mag.iter_mut() {
+            let t = *d as u64 * factor as u64 + carry;
+            *d = t as u32;
+            carry = t >> 32;
+        }
+        if carry != 0 {
+            self.mag.push(carry as u32);
+        }
+    }
+
+    // Divides the magnitude by a single limb in place, returning the remainder.
+    fn div_rem_small(&mut self, d: u32) -> u32 {
+        let mut rem = 0u64;
+        for limb in self.mag.iter_mut().rev() {
+            let cur = (rem << 32) | *limb as u64;
+            *limb = (cur / d as u64) as u32;
+            rem = cur % d as u64;
+        }
+        *self = Self::signed(self.neg, std::mem::take(&mut self.mag));
+        rem as u32
+    }
+
+    // self + (-1)^rhs_neg |rhs|, so that subtraction needs no negated copy.
+    fn add_signed(&self, rhs: &Self, rhs_neg: bool) -> Self {
+        if self.neg == rhs_neg {
+            return Self::signed(self.neg, mag_add(&self.mag, &rhs.mag));
+        }
+        match mag_cmp(&self.mag, &rhs.mag) {
+            Ordering::Less => Self::signed(rhs_neg, mag_sub(&rhs.mag, &self.mag)),
+            _ => Self::signed(self.neg, mag_sub(&self.mag, &rhs.mag)),
+        }
+    }
+}
+
+// Magnitudes have no trailing zero limbs, so a longer one is larger.
+fn mag_cmp(a: &[u32], b: &[u32]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+#[allow(clippy::needless_range_loop)]
+fn mag_add(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut out = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for i in 0..long.len() {
+        let t = long[i] as u64 + short.get(i).copied().unwrap_or(0) as u64 + carry;
+        out.push(t as u32);
+        carry = t >> 32;
+    }
+    if carry != 0 {
+        out.push(carry as u32);
+    }
+    out
+}
+
+// Requires a >= b.
+#[allow(clippy::needless_range_loop)]
+fn mag_sub(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut out = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for i in 0..a.len() {
+        let t = a[i] as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        out.push(t as u32);
+        borrow = if t < 0 { 1 } else { 0 };
+    }
+    out
+}
+
+fn mag_mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut out = vec![0u32; a.len() + b.len()];
+    for i in 0..a.len() {
+        let mut carry = 0u64;
+        for j in 0..b.len() {
+            let t = a[i] as u64 * b[j] as u64 + out[i + j] as u64 + carry;
+            out[i + j] = t as u32;
+            carry = t >> 32;
+        }
+        out[i + b.len()] = carry as u32;
+    }
+    out
+}
+
+// Knuth's Algorithm D on normalized 32-bit limbs; v is nonzero.
+fn mag_div_rem(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let (m, n) = (u.len(), v.len());
+    if mag_cmp(u, v) == Ordering::Less {
+        return (Vec::new(), u.to_vec());
+    }
+    let mut q = vec![0; m - n + 1];
+
+    if n == 1 {
+        let d = v[0] as u64;
+        let mut rem = 0u64;
+        for i in (0..m).rev() {
+            let cur = (rem << 32) | u[i] as u64;
+            q[i] = (cur / d) as u32;
+            rem = cur % d;
+        }
+        return (q, vec![rem as u32]);
+    }
+
+    let s = v[n - 1].leading_zeros();
+    let shl = |hi: u32, lo: u32| (((hi as u64) << s) | ((lo as u64) >> (32 - s))) as u32;
+    let mut vn = vec![0u32; n];
+    for i in (1..n).rev() {
+        vn[i] = shl(v[i], v[i - 1]);
+    }
+    vn[0] = v[0] << s;
+    let mut un = vec![0u32; m + 1];
+    un[m] = ((u[m - 1] as u64) >> (32 - s)) as u32;
+    for i in (1..m).rev() {
+        un[i] = shl(u[i], u[i - 1]);
+    }
+    un[0] = u[0] << s;
+
+    for j in (0..=m - n).rev() {
+        let num = ((un[j + n] as u64) << 32) | un[j + n - 1] as u64;
+        let mut qhat = num / vn[n - 1] as u64;
+        let mut rhat = num % vn[n - 1] as u64;
+        while qhat >= BASE || qhat * vn[n - 2] as u64 > ((rhat << 32) | un[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += vn[n - 1] as u64;
+            if rhat >= BASE {
+                break;
+            }
+        }
+
+        let mut k = 0i64;
+        for i in 0..n {
+            let p = qhat * vn[i] as u64;
+            let t = un[i + j] as i64 - k - (p & 0xffff_ffff) as i64;
+            un[i + j] = t as u32;
+            k = (p >> 32) as i64 - (t >> 32);
+        }
+        let t = un[j + n] as i64 - k;
+        un[j + n] = t as u32;
+
+        q[j] = qhat as u32;
+        if t < 0 {
+            q[j] = q[j].wrapping_sub(1);
+            let mut carry = 0u64;
+            for i in 0..n {
+                let t = un[i + j] as u64 + vn[i] as u64 + carry;
+                un[i + j] = t as u32;
+                carry = t >> 32;
+            }
+            un[j + n] = un[j + n].wrapping_add(carry as u32);
+        }
+    }
+
+    let r = (0..n).map(|i| ((un[i] as u64 >> s) | ((un[i + 1] as u64) << (32 - s))) as u32).collect();
+    (q, r)
+}
+
+impl From<i64> for BigInt {
+    fn from(n: i64) -> Self {
+        let abs = n.unsigned_abs();
+        Self::signed(n < 0, vec![abs as u32, (abs >> 32) as u32])
+    }
+}
+
+impl From<BigInt> for f32 {
+    fn from(n: BigInt) -> f32 {
+        n.to_f64() as f32
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let (neg, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("Invalid integer: {:?}", s));
+        }
+        let mut n = Self::default();
+        for b in digits.bytes() {
+            n.mul_small_add(10, (b - b'0') as u32);
+        }
+        Ok(Self::signed(neg, n.mag))
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn add(self, rhs: &BigInt) -> BigInt {
+        self.add_signed(rhs, rhs.neg)
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self.add_signed(rhs, !rhs.neg)
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::signed(self.neg != rhs.neg, mag_mul(&self.mag, &rhs.mag))
+    }
+}
+
+/// Truncates toward zero.
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Add for BigInt {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self { &self + &rhs }
+}
+
+impl Sub for BigInt {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self { &self - &rhs }
+}
+
+impl Mul for BigInt {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self { &self * &rhs }
+}
+
+/// Truncates toward zero.
+impl Div for BigInt {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self { &self / &rhs }
+}
+
+impl Neg for BigInt {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::signed(!self.neg, self.mag)
+    }
+}
+
+impl AddAssign for BigInt {
+    fn add_assign(&mut self, rhs: Self) { *self = &*self + &rhs; }
+}
+impl SubAssign for BigInt {
+    fn sub_assign(&mut self, rhs: Self) { *self = &*self - &rhs; }
+}
+impl MulAssign for BigInt {
+    fn mul_assign(&mut self, rhs: Self) { *self = &*self * &rhs; }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.neg, other.neg) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => mag_cmp(&self.mag, &other.mag),
+            (true, true) => mag_cmp(&other.mag, &self.mag),
+        }
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.pad("0");
+        }
+        let mut rest = self.abs();
+        let mut chunks = Vec::new();
+        while !rest.is_zero() {
+            chunks.push(rest.div_rem_small(1_000_000_000));
+        }
+        let mut out = format!("{}{}", if self.neg { "-" } else { "" }, chunks.pop().unwrap_or(0));
+        for c in chunks.iter().rev() {
+            out += &format!("{:09}", c);
+        }
+        f.pad(&out)
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BigInt({})", self)
+    }
+}
+
+impl Semiring for BigInt {
+    fn zero() -> Self { Self::default() }
+    fn one() -> Self { Self::from(1) }
+}
+
+impl Ring for BigInt {}
+
+impl Matrix<BigInt> {
+    /// Exact, by Bareiss elimination, with entries no larger than the
+    /// minors of the matrix.
+    pub fn determinant(&self) -> BigInt {
+        self.bareiss()
+    }
+}
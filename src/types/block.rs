@@ -0,0 +1,196 @@
+use super::field::Field;
+use crate::{Matrix, Vector};
+
+/// A matrix partitioned into a grid of blocks. All blocks in a block row share
+/// their row count, all blocks in a block column share their column count.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockMatrix<K: Field> {
+    pub blocks: Vec<Vec<Matrix<K>>>,
+    pub row_sizes: Vec<usize>,
+    pub col_sizes: Vec<usize>,
+}
+
+/// Block LU without block pivoting: A = L U where L is block unit lower
+/// triangular and the diagonal blocks of U are the successive Schur complements.
+#[derive(Debug, Clone)]
+pub struct BlockLu<K: Field> {
+    pub lower: BlockMatrix<K>,
+    pub upper: BlockMatrix<K>,
+    diag_inv: Vec<Matrix<K>>,
+}
+
+impl<K: Field> BlockMatrix<K> {
+    pub fn from(blocks: Vec<Vec<Matrix<K>>>) -> Self {
+        let row_sizes: Vec<usize> = blocks.iter().map(|row| row[0].rows).collect();
+        let col_sizes: Vec<usize> = blocks.first().map_or(Vec::new(), |row| row.iter().map(|b| b.cols).collect());
+
+        for (i, row) in blocks.iter().enumerate() {
+            assert_eq!(row.len(), col_sizes.len(), "Block row {} has {} blocks, expected {}", i, row.len(), col_sizes.len());
+            for (j, b) in row.iter().enumerate() {
+                assert_eq!(b.shape(), (row_sizes[i], col_sizes[j]),
+                    "Block ({}, {}) has shape {:?}, expected {:?}", i, j, b.shape(), (row_sizes[i], col_sizes[j]));
+            }
+        }
+        BlockMatrix { blocks, row_sizes, col_sizes }
+    }
+
+    /// Cuts a dense matrix along the given block sizes.
+    pub fn split(dense: &Matrix<K>, row_sizes: &[usize], col_sizes: &[usize]) -> Self {
+        assert_eq!(row_sizes.iter().sum::<usize>(), dense.rows, "Block rows do not add up to {}", dense.rows);
+        assert_eq!(col_sizes.iter().sum::<usize>(), dense.cols, "Block columns do not add up to {}", dense.cols);
+
+        let mut blocks = Vec::new();
+        let mut row = 0;
+        for &r in row_sizes {
+            let mut block_row = Vec::new();
+            let mut col = 0;
+            for &c in col_sizes {
+                block_row.push(dense.submatrix(row, col, r, c));
+                col += c;
+            }
+            blocks.push(block_row);
+            row += r;
+        }
+        BlockMatrix { blocks, row_sizes: row_sizes.to_vec(), col_sizes: col_sizes.to_vec() }
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.row_sizes.iter().sum(), self.col_sizes.iter().sum())
+    }
+
+    pub fn block_shape(&self) -> (usize, usize) {
+        (self.row_sizes.len(), self.col_sizes.len())
+    }
+
+    pub fn get_block(&self, i: usize, j: usize) -> &Matrix<K> {
+        &self.blocks[i][j]
+    }
+
+    pub fn to_dense(&self) -> Matrix<K> {
+        let rows: Vec<Vec<&Matrix<K>>> = self.blocks.iter().map(|row| row.iter().collect()).collect();
+        let rows: Vec<&[&Matrix<K>]> = rows.iter().map(|row| row.as_slice()).collect();
+        Matrix::block(&rows)
+    }
+
+    /// Block-wise product; the column partition of `self` must match the row
+    /// partition of `other`.
+    pub fn mul_mat(&self, other: &BlockMatrix<K>) -> BlockMatrix<K> {
+        assert_eq!(self.col_sizes, other.row_sizes, "Error: Block partitions do not conform!");
+        let mut blocks = Vec::with_capacity(self.row_sizes.len());
+
+        for i in 0..self.row_sizes.len() {
+            let mut block_row = Vec::with_capacity(other.col_sizes.len());
+            for j in 0..other.col_sizes.len() {
+                let mut sum = Matrix::from(vec![vec![K::zero(); other.col_sizes[j]]; self.row_sizes[i]]);
+                for k in 0..self.col_sizes.len() {
+                    sum.add(self.blocks[i][k].mul_mat(other.blocks[k][j].clone()));
+                }
+                block_row.push(sum);
+            }
+            blocks.push(block_row);
+        }
+        BlockMatrix { blocks, row_sizes: self.row_sizes.clone(), col_sizes: other.col_sizes.clone() }
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn mul_vec(&self, vec: Vector<K>) -> Vector<K> {
+        let parts = split_vector(&vec, &self.col_sizes);
+        let mut result = Vec::with_capacity(self.shape().0);
+        for i in 0..self.row_sizes.len() {
+            let mut sum = Vector::from(vec![K::zero(); self.row_sizes[i]]);
+            for j in 0..self.col_sizes.len() {
+                sum.add(self.blocks[i][j].mul_vec(parts[j].clone()));
+            }
+            result.extend(sum.data);
+        }
+        Vector::from(result)
+    }
+}
+
+impl<K: Field + PartialOrd> BlockMatrix<K> {
+    /// Block Gaussian elimination: for each diagonal block A_kk, the blocks
+    /// below it are eliminated with L_ik = A_ik A_kk^-1 and the trailing blocks
+    /// become the Schur complement A_ij - L_ik A_kj. Fails if a Schur
+    /// complement on the diagonal is singular.
+    #[allow(clippy::needless_range_loop)]
+    pub fn lu(&self) -> Result<BlockLu<K>, String> {
+        let nb = self.row_sizes.len();
+        if self.row_sizes != self.col_sizes {
+            return Err("Block LU needs square diagonal blocks".to_string());
+        }
+
+        let mut u = self.blocks.clone();
+        let mut l: Vec<Vec<Matrix<K>>> = (0..nb)
+            .map(|i| (0..nb).map(|j| {
+                if i == j { Matrix::identity(self.row_sizes[i]) }
+                else { Matrix::from(vec![vec![K::zero(); self.row_sizes[j]]; self.row_sizes[i]]) }
+            }).collect())
+            .collect();
+        let mut diag_inv = Vec::with_capacity(nb);
+
+        for k in 0..nb {
+            let inv = u[k][k].clone().inverse()
+                .map_err(|e| format!("Schur complement block {} is singular: {}", k, e))?;
+            for i in k + 1..nb {
+                let l_ik = u[i][k].mul_mat(inv.clone());
+                for j in k + 1..nb {
+                    let update = l_ik.mul_mat(u[k][j].clone());
+                    u[i][j].sub(update);
+                }
+                u[i][k] = Matrix::from(vec![vec![K::zero(); self.row_sizes[k]]; self.row_sizes[i]]);
+                l[i][k] = l_ik;
+            }
+            diag_inv.push(inv);
+        }
+
+        Ok(BlockLu {
+            lower: BlockMatrix::from(l),
+            upper: BlockMatrix::from(u),
+            diag_inv,
+        })
+    }
+}
+
+impl<K: Field + PartialOrd> BlockLu<K> {
+    pub fn solve(&self, b: &Vector<K>) -> Vector<K> {
+        let sizes = &self.lower.row_sizes;
+        let nb = sizes.len();
+        let mut y = split_vector(b, sizes);
+
+        for i in 0..nb {
+            for k in 0..i {
+                let update = self.lower.blocks[i][k].mul_vec(y[k].clone());
+                y[i].sub(update);
+            }
+        }
+        for i in (0..nb).rev() {
+            for j in i + 1..nb {
+                let update = self.upper.blocks[i][j].mul_vec(y[j].clone());
+                y[i].sub(update);
+            }
+            y[i] = self.diag_inv[i].mul_vec(y[i].clone());
+        }
+
+        Vector::from(y.into_iter().flat_map(|part| part.data).collect::<Vec<K>>())
+    }
+
+    /// det(A) as the product of the determinants of the Schur complements.
+    pub fn determinant(&self) -> K {
+        let mut det = K::one();
+        for k in 0..self.upper.row_sizes.len() {
+            det *= self.upper.blocks[k][k].clone().determinant();
+        }
+        det
+    }
+}
+
+fn split_vector<K: Field>(vec: &Vector<K>, sizes: &[usize]) -> Vec<Vector<K>> {
+    assert_eq!(vec.size(), sizes.iter().sum::<usize>(), "Vector size does not match the block partition");
+    let mut parts = Vec::with_capacity(sizes.len());
+    let mut start = 0;
+    for &s in sizes {
+        parts.push(Vector::from(vec.data[start..start + s].to_vec()));
+        start += s;
+    }
+    parts
+}
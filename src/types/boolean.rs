@@ -0,0 +1,46 @@
+use std::fmt;
+use std::ops::{Add, Mul, AddAssign, MulAssign};
+use super::field::Semiring;
+
+/// The Boolean semiring: `+` is or, `*` is and. Products of adjacency
+/// matrices answer "is there a walk", powers give reachability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Boolean(pub bool);
+
+impl Add for Boolean {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Boolean(self.0 || rhs.0)
+    }
+}
+
+impl Mul for Boolean {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Boolean(self.0 && rhs.0)
+    }
+}
+
+impl AddAssign for Boolean {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+impl MulAssign for Boolean {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl From<bool> for Boolean {
+    fn from(b: bool) -> Self {
+        Boolean(b)
+    }
+}
+
+impl fmt::Display for Boolean {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", if self.0 { 1 } else { 0 })
+    }
+}
+
+impl Semiring for Boolean {
+    fn zero() -> Self { Boolean(false) }
+    fn one() -> Self { Boolean(true) }
+}
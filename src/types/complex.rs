@@ -1,78 +1,313 @@
 use std::fmt;
+use std::str::FromStr;
 use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign};
-use super::field::Field;
+use super::field::{Semiring, Ring, Field};
+use super::real::Real;
+use crate::Matrix;
 
+/// Complex number over f32 or f64. The multivalued functions (`arg`, `ln`,
+/// `sqrt`, `powf`, `powc`) return the principal value, with the branch cut
+/// along the negative real axis.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-pub struct Complex {
-    pub re: f32,
-    pub im: f32,
+pub struct Complex<T = f32> {
+    pub re: T,
+    pub im: T,
 }
 
-impl Complex {
-    pub fn from(re: f32, im: f32) -> Self {
+impl<T: Real> Complex<T> {
+    pub fn from(re: T, im: T) -> Self {
         Self { re, im }
     }
 
-    pub fn modulus(self) -> f32 {
-        (self.re * self.re + self.im * self.im).sqrt()
+    /// The imaginary unit.
+    pub fn i() -> Self {
+        Self::from(T::ZERO, T::ONE)
+    }
+
+    /// |z| by `hypot`, so it neither overflows nor underflows when |z| is
+    /// representable, and is +inf whenever either part is infinite.
+    pub fn modulus(self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    /// |z|^2, without the square root. Unlike `modulus` this can overflow.
+    pub fn norm_sqr(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// An infinity in C99 Annex G terms: either part infinite, even if the
+    /// other is NaN.
+    pub fn is_infinite(self) -> bool {
+        self.re.is_infinite() || self.im.is_infinite()
+    }
+
+    /// Not infinite, and at least one part NaN.
+    pub fn is_nan(self) -> bool {
+        !self.is_infinite() && (self.re.is_nan() || self.im.is_nan())
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    pub fn conj(self) -> Self {
+        Self::from(self.re, -self.im)
+    }
+
+    /// Angle in (-π, π].
+    pub fn arg(self) -> T {
+        self.im.atan2(self.re)
+    }
+
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Self::from(r * theta.cos(), r * theta.sin())
+    }
+
+    /// (modulus, arg).
+    pub fn to_polar(self) -> (T, T) {
+        (self.modulus(), self.arg())
+    }
+
+    pub fn exp(self) -> Self {
+        Self::from_polar(self.re.exp(), self.im)
+    }
+
+    pub fn ln(self) -> Self {
+        Self::from(self.modulus().ln(), self.arg())
+    }
+
+    /// Principal square root, computed without going through the angle so
+    /// that real and imaginary inputs give exact results.
+    pub fn sqrt(self) -> Self {
+        if self.re == T::ZERO && self.im == T::ZERO {
+            return Self::from(T::ZERO, self.im);
+        }
+        let half = T::from_f64(0.5);
+        let t = ((self.re.abs() + self.modulus()) * half).sqrt();
+        if self.re >= T::ZERO {
+            Self::from(t, self.im / (t + t))
+        } else {
+            Self::from(self.im.abs() / (t + t), t.copysign(self.im))
+        }
+    }
+
+    /// z^n for a real exponent.
+    pub fn powf(self, n: T) -> Self {
+        if self.re == T::ZERO && self.im == T::ZERO {
+            return if n == T::ZERO { Self::one() } else { Self::zero() };
+        }
+        let (r, theta) = self.to_polar();
+        Self::from_polar(r.powf(n), theta * n)
+    }
+
+    /// z^w = exp(w ln z).
+    pub fn powc(self, w: Self) -> Self {
+        if w == Self::zero() {
+            return Self::one();
+        }
+        if self == Self::zero() && w.re > T::ZERO {
+            return Self::zero();
+        }
+        (w * self.ln()).exp()
+    }
+
+    pub fn sin(self) -> Self {
+        Self::from(self.re.sin() * self.im.cosh(), self.re.cos() * self.im.sinh())
+    }
+
+    pub fn cos(self) -> Self {
+        Self::from(self.re.cos() * self.im.cosh(), -(self.re.sin() * self.im.sinh()))
+    }
+
+    /// tan z = -i tanh(iz).
+    pub fn tan(self) -> Self {
+        let t = Self::from(-self.im, self.re).tanh();
+        Self::from(t.im, -t.re)
+    }
+
+    pub fn sinh(self) -> Self {
+        Self::from(self.re.sinh() * self.im.cos(), self.re.cosh() * self.im.sin())
+    }
+
+    pub fn cosh(self) -> Self {
+        Self::from(self.re.cosh() * self.im.cos(), self.re.sinh() * self.im.sin())
+    }
+
+    /// (sinh 2a + i sin 2b) / (cosh 2a + cos 2b) for z = a + bi. Once cosh 2a
+    /// overflows the quotient is ±1 to working precision, so that is
+    /// returned instead of inf / inf.
+    pub fn tanh(self) -> Self {
+        let (a2, b2) = (self.re + self.re, self.im + self.im);
+        let denom = a2.cosh() + b2.cos();
+        if denom.is_infinite() {
+            return Self::from(T::ONE.copysign(self.re), T::ZERO.copysign(b2.sin()));
+        }
+        Self::from(a2.sinh() / denom, b2.sin() / denom)
     }
 }
 
-impl Add for Complex {
+impl<T: Real> Add for Complex<T> {
     type Output = Self;
     fn add(self, rhs: Self) -> Self {
         Self::from(self.re + rhs.re, self.im + rhs.im)
     }
 }
 
-impl Sub for Complex {
+impl<T: Real> Sub for Complex<T> {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self {
         Self::from(self.re - rhs.re, self.im - rhs.im)
     }
 }
 
-impl Mul for Complex {
+/// Textbook product, with the C99 Annex G recovery when both parts come out
+/// NaN: an infinite operand times a nonzero one is infinite, with the
+/// direction of the product of the directions.
+impl<T: Real> Mul for Complex<T> {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self {
-        Self::from(
-            self.re * rhs.re - self.im * rhs.im,
-            self.re * rhs.im + self.im * rhs.re,
-        )
+        let (mut a, mut b, mut c, mut d) = (self.re, self.im, rhs.re, rhs.im);
+        let (ac, bd, ad, bc) = (a * c, b * d, a * d, b * c);
+        let product = Self::from(ac - bd, ad + bc);
+        if !(product.re.is_nan() && product.im.is_nan()) {
+            return product;
+        }
+
+        let mut recalc = false;
+        if self.is_infinite() {
+            (a, b) = (unit_box(a), unit_box(b));
+            (c, d) = (nan_to_zero(c), nan_to_zero(d));
+            recalc = true;
+        }
+        if rhs.is_infinite() {
+            (c, d) = (unit_box(c), unit_box(d));
+            (a, b) = (nan_to_zero(a), nan_to_zero(b));
+            recalc = true;
+        }
+        // Finite operands whose partial products overflowed.
+        if !recalc && [ac, bd, ad, bc].iter().any(|p| p.is_infinite()) {
+            (a, b, c, d) = (nan_to_zero(a), nan_to_zero(b), nan_to_zero(c), nan_to_zero(d));
+            recalc = true;
+        }
+        if !recalc {
+            return product;
+        }
+        Self::from(T::INFINITY * (a * c - b * d), T::INFINITY * (a * d + b * c))
     }
 }
 
-impl Div for Complex {
+/// Baudin and Smith's robust variant of Smith's algorithm ("A Robust Complex
+/// Division in Scilab", 2012): operands are scaled by powers of two out of
+/// the overflow and underflow ranges, and the quotient is formed from the
+/// ratio of the smaller to the larger part of the divisor, so it is accurate
+/// wherever it is representable. NaN results are then recovered as in C99
+/// Annex G: x / 0 is infinite for nonzero x, inf / finite is infinite and
+/// finite / inf is zero, all with signs following the operands.
+impl<T: Real> Div for Complex<T> {
     type Output = Self;
     fn div(self, rhs: Self) -> Self {
-        let denom = rhs.re * rhs.re + rhs.im * rhs.im;
-        Self::from(
-            (self.re * rhs.re + self.im * rhs.im) / denom,
-            (self.im * rhs.re - self.re * rhs.im) / denom,
-        )
+        let quotient = robust_div(self, rhs);
+        if !(quotient.re.is_nan() && quotient.im.is_nan()) {
+            return quotient;
+        }
+
+        let (a, b, c, d) = (self.re, self.im, rhs.re, rhs.im);
+        if c == T::ZERO && d == T::ZERO && !(a.is_nan() && b.is_nan()) {
+            let inf = T::INFINITY.copysign(c);
+            Self::from(inf * a, inf * b)
+        } else if self.is_infinite() && rhs.is_finite() {
+            let (a, b) = (unit_box(a), unit_box(b));
+            Self::from(T::INFINITY * (a * c + b * d), T::INFINITY * (b * c - a * d))
+        } else if rhs.is_infinite() && self.is_finite() {
+            let (c, d) = (unit_box(c), unit_box(d));
+            Self::from(T::ZERO * (a * c + b * d), T::ZERO * (b * c - a * d))
+        } else {
+            quotient
+        }
     }
 }
 
-impl Neg for Complex {
+fn robust_div<T: Real>(x: Complex<T>, y: Complex<T>) -> Complex<T> {
+    let (mut a, mut b, mut c, mut d) = (x.re, x.im, y.re, y.im);
+    let two = T::ONE + T::ONE;
+    let ab = if a.abs() > b.abs() { a.abs() } else { b.abs() };
+    let cd = if c.abs() > d.abs() { c.abs() } else { d.abs() };
+    let (half_max, tiny) = (T::MAX / two, T::MIN_POSITIVE * two / T::EPSILON);
+    let boost = two / (T::EPSILON * T::EPSILON);
+    let mut scale = T::ONE;
+
+    if ab >= half_max {
+        (a, b, scale) = (a / two, b / two, scale * two);
+    }
+    if cd >= half_max {
+        (c, d, scale) = (c / two, d / two, scale / two);
+    }
+    if ab <= tiny {
+        (a, b, scale) = (a * boost, b * boost, scale / boost);
+    }
+    if cd <= tiny {
+        (c, d, scale) = (c * boost, d * boost, scale * boost);
+    }
+
+    let (e, f) = if d.abs() <= c.abs() {
+        smith_step(a, b, c, d)
+    } else {
+        let (e, f) = smith_step(b, a, d, c);
+        (e, -f)
+    };
+    Complex::from(e * scale, f * scale)
+}
+
+// (a + bi) / (c + di) for |d| <= |c|.
+fn smith_step<T: Real>(a: T, b: T, c: T, d: T) -> (T, T) {
+    let r = d / c;
+    let den = c + d * r;
+    (smith_part(a, b, c, d, r, den), smith_part(b, -a, c, d, r, den))
+}
+
+// (a + b r) / den, reordered when b r underflows or r is zero so that the
+// small term is not lost. Dividing rather than multiplying by 1 / den
+// saves a rounding, which makes quotients like (1 + i) / (2 + i) exact to
+// the last bit.
+fn smith_part<T: Real>(a: T, b: T, c: T, d: T, r: T, den: T) -> T {
+    if r != T::ZERO {
+        let br = b * r;
+        if br != T::ZERO { (a + br) / den } else { a / den + (b / den) * r }
+    } else {
+        (a + d * (b / c)) / den
+    }
+}
+
+// ±1 for an infinite part and ±0 otherwise, keeping the sign.
+fn unit_box<T: Real>(x: T) -> T {
+    (if x.is_infinite() { T::ONE } else { T::ZERO }).copysign(x)
+}
+
+fn nan_to_zero<T: Real>(x: T) -> T {
+    if x.is_nan() { T::ZERO.copysign(x) } else { x }
+}
+
+impl<T: Real> Neg for Complex<T> {
     type Output = Self;
     fn neg(self) -> Self {
         Self::from(-self.re, -self.im)
     }
 }
 
-impl AddAssign for Complex {
+impl<T: Real> AddAssign for Complex<T> {
     fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
 }
-impl SubAssign for Complex {
+impl<T: Real> SubAssign for Complex<T> {
     fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
 }
-impl MulAssign for Complex {
+impl<T: Real> MulAssign for Complex<T> {
     fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
 }
 
-impl fmt::Display for Complex {
+impl<T: Real> fmt::Display for Complex<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.im >= 0.0 {
+        // Checks the sign bit, so -0 prints as `-0i` rather than `+-0i`.
+        if T::ONE.copysign(self.im) > T::ZERO {
             write!(f, "{}+{}i", self.re, self.im)
         } else {
             write!(f, "{}{}i", self.re, self.im)
@@ -80,22 +315,244 @@ impl fmt::Display for Complex {
     }
 }
 
-impl Into<f32> for Complex {
-    fn into(self) -> f32 {
-        self.modulus()
+/// Parses `a+bi`, `a-bi`, `a`, `bi`, `i` and `-i`, with `j` accepted for `i`
+/// and whitespace ignored, so `3 - 4i` reads the same as `3-4i`.
+impl<T: Real> FromStr for Complex<T> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let compact: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        let parse = |part: &str| part.parse::<T>().map_err(|_| format!("Invalid complex number: {:?}", s));
+        if compact.is_empty() {
+            return Err("Empty complex number".to_string());
+        }
+
+        let body = match compact.strip_suffix('i').or_else(|| compact.strip_suffix('j')) {
+            Some(body) => body,
+            None => return Ok(Self::from(parse(&compact)?, T::ZERO)),
+        };
+        // The imaginary part starts at the last sign that is not an exponent's.
+        let bytes = body.as_bytes();
+        let split = (1..bytes.len())
+            .rev()
+            .find(|&i| (bytes[i] == b'+' || bytes[i] == b'-') && !matches!(bytes[i - 1], b'e' | b'E'))
+            .unwrap_or(0);
+        let (re, im) = body.split_at(split);
+        let re = if re.is_empty() { T::ZERO } else { parse(re)? };
+        let im = match im {
+            "" | "+" => T::ONE,
+            "-" => -T::ONE,
+            _ => parse(im)?,
+        };
+        Ok(Self::from(re, im))
+    }
+}
+
+impl<T: Real> From<Complex<T>> for f32 {
+    fn from(z: Complex<T>) -> f32 {
+        z.modulus().to_f64() as f32
     }
 }
 
-impl Field for Complex {
-    fn zero() -> Self { Self::from(0.0, 0.0) }
-    fn one() -> Self { Self::from(1.0, 0.0) }
+impl<T: Real> Semiring for Complex<T> {
+    fn zero() -> Self { Self::from(T::ZERO, T::ZERO) }
+    fn one() -> Self { Self::from(T::ONE, T::ZERO) }
+}
+
+impl<T: Real> Ring for Complex<T> {}
+
+impl<T: Real> Field for Complex<T> {
     fn fma(self, b: Self, c: Self) -> Self { self * b + c }
     fn abs(self) -> Self {
-        Self::from(self.modulus(), 0.0)
+        Self::from(self.modulus(), T::ZERO)
     }
     fn sqrt(self) -> Self {
-        let r = self.modulus().sqrt();
-        let theta = self.im.atan2(self.re) / 2.0;
-        Self::from(r * theta.cos(), r * theta.sin())
+        Complex::sqrt(self)
+    }
+}
+
+impl<T: Real> Matrix<Complex<T>> {
+    /// A^H: the transpose with every entry conjugated.
+    pub fn conjugate_transpose(&self) -> Matrix<Complex<T>> {
+        let mut result = self.transpose();
+        for row in result.data.iter_mut() {
+            for z in row.iter_mut() {
+                *z = z.conj();
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Case<T> = (Complex<T>, Complex<T>, Complex<T>);
+
+    fn c<T: Real>(re: T, im: T) -> Complex<T> {
+        Complex::from(re, im)
+    }
+
+    // Bitwise, so the signs of zeros and infinities count. Any NaN matches
+    // any NaN: the sign and payload of a NaN produced by arithmetic are not
+    // specified.
+    fn same<T: Real>(x: T, y: T) -> bool {
+        if x.is_nan() || y.is_nan() {
+            x.is_nan() && y.is_nan()
+        } else {
+            x.to_f64().to_bits() == y.to_f64().to_bits()
+        }
     }
-}
\ No newline at end of file
+
+    fn check<T: Real>(op: &str, f: impl Fn(Complex<T>, Complex<T>) -> Complex<T>, table: &[Case<T>]) {
+        for &(x, y, expected) in table {
+            let got = f(x, y);
+            assert!(
+                same(got.re, expected.re) && same(got.im, expected.im),
+                "{:?} {} {:?} = {:?}, expected {:?}", x, op, y, got, expected
+            );
+        }
+    }
+
+    fn p2_32(e: i32) -> f32 {
+        if e >= -126 { f32::from_bits(((e + 127) as u32) << 23) } else { f32::from_bits(1 << (e + 149)) }
+    }
+
+    fn p2_64(e: i32) -> f64 {
+        if e >= -1022 { f64::from_bits(((e + 1023) as u64) << 52) } else { f64::from_bits(1 << (e + 1074)) }
+    }
+
+    // C99 Annex G: x / 0 is infinite for nonzero x, inf / finite is infinite
+    // and finite / inf is zero, with signs following the operands.
+    fn annex_g_division<T: Real>() -> Vec<Case<T>> {
+        let (zero, one, two, inf, nan) = (T::ZERO, T::ONE, T::ONE + T::ONE, T::INFINITY, T::NAN);
+        vec![
+            (c(one, one), c(zero, zero), c(inf, inf)),
+            (c(one, -one), c(-zero, zero), c(-inf, inf)),
+            (c(zero, zero), c(zero, zero), c(nan, nan)),
+            (c(nan, one), c(zero, zero), c(nan, inf)),
+            (c(nan, nan), c(zero, zero), c(nan, nan)),
+            (c(inf, zero), c(one, one), c(inf, -inf)),
+            (c(inf, nan), c(two, zero), c(inf, nan)),
+            (c(one, one), c(inf, inf), c(zero, zero)),
+            (c(one, one), c(-inf, nan), c(-zero, -zero)),
+            (c(one, zero), c(-inf, zero), c(-zero, -zero)),
+            (c(nan, nan), c(inf, inf), c(nan, nan)),
+        ]
+    }
+
+    // C99 Annex G: an infinite operand times a nonzero one is infinite, even
+    // when the textbook formula gives NaN in both parts; inf * 0 is NaN.
+    fn annex_g_multiplication<T: Real>() -> Vec<Case<T>> {
+        let (zero, one, inf, nan, max) = (T::ZERO, T::ONE, T::INFINITY, T::NAN, T::MAX);
+        vec![
+            (c(inf, zero), c(zero, zero), c(nan, nan)),
+            (c(inf, zero), c(one, one), c(inf, inf)),
+            (c(inf, inf), c(one, one), c(nan, inf)),
+            (c(nan, inf), c(one, zero), c(nan, inf)),
+            (c(inf, nan), c(nan, one), c(nan, inf)),
+            (c(zero, zero), c(inf, nan), c(nan, nan)),
+            (c(one, one), c(nan, nan), c(nan, nan)),
+            (c(max, max), c(max, -max), c(inf, nan)),
+            (c(-zero, zero), c(one, zero), c(-zero, zero)),
+            (c(-zero, -zero), c(-zero, -zero), c(zero, zero)),
+        ]
+    }
+
+    // |z| of infinities is +inf even next to a NaN, and the hypot form
+    // neither overflows nor underflows on its way to a representable result.
+    fn annex_g_modulus<T: Real>() -> Vec<(Complex<T>, T)> {
+        let (zero, one, inf, nan) = (T::ZERO, T::ONE, T::INFINITY, T::NAN);
+        vec![
+            (c(inf, nan), inf),
+            (c(nan, -inf), inf),
+            (c(-inf, one), inf),
+            (c(nan, zero), nan),
+            (c(-zero, -zero), zero),
+            (c(-one, zero), one),
+        ]
+    }
+
+    fn check_modulus<T: Real>(table: &[(Complex<T>, T)]) {
+        for &(x, expected) in table {
+            assert!(same(x.modulus(), expected), "|{:?}| = {:?}, expected {:?}", x, x.modulus(), expected);
+        }
+    }
+
+    // Baudin and Smith's ten hard quotients, each correctly rounded.
+    #[test]
+    fn hard_division_f64() {
+        let p = p2_64;
+        check("/", |x, y| x / y, &[
+            (c(1.0, 1.0), c(1.0, p(1023)), c(p(-1023), -p(-1023))),
+            (c(1.0, 1.0), c(p(-1023), p(-1023)), c(p(1023), 0.0)),
+            (c(p(1023), p(-1023)), c(p(677), p(-677)), c(p(346), -p(-1008))),
+            (c(p(1023), p(1023)), c(1.0, 1.0), c(p(1023), 0.0)),
+            (c(p(1020), p(-844)), c(p(656), p(-780)), c(p(364), -p(-1072))),
+            (c(p(-71), p(1021)), c(p(1001), p(-323)), c(p(-1072), p(20))),
+            (c(p(-347), p(-54)), c(p(-1037), p(-1058)), c(p(962) - p(920), p(983) - p(941))),
+            (c(p(-1074), p(-1074)), c(p(-1073), p(-1074)), c(0.6, 0.2)),
+            (c(p(1015), p(-989)), c(p(1023), p(1023)), c(p(-9), -p(-9))),
+            (c(p(-622), p(-1071)), c(p(-343), p(-798)), c(p(-279), p(-728) - p(-734))),
+        ]);
+    }
+
+    // The same constructions moved into f32's exponent range.
+    #[test]
+    fn hard_division_f32() {
+        let p = p2_32;
+        check("/", |x, y| x / y, &[
+            (c(1.0, 1.0), c(1.0, p(127)), c(p(-127), -p(-127))),
+            (c(1.0, 1.0), c(p(-127), p(-127)), c(p(127), 0.0)),
+            (c(p(127), p(-127)), c(p(84), p(-84)), c(p(43), -p(-125))),
+            (c(p(127), p(127)), c(1.0, 1.0), c(p(127), 0.0)),
+            (c(p(124), p(-104)), c(p(82), p(-96)), c(p(42), -p(-136))),
+            (c(p(-9), p(125)), c(p(121), p(-40)), c(p(-130), p(4))),
+            (c(p(-43), p(-7)), c(p(-129), p(-131)), c(f32::from_bits(0x7b70_f0f1), f32::from_bits(0x7c70_f0f1))),
+            (c(p(-149), p(-149)), c(p(-148), p(-149)), c(0.6, 0.2)),
+            (c(p(119), p(-123)), c(p(127), p(127)), c(p(-9), -p(-9))),
+            (c(p(-78), p(-134)), c(p(-43), p(-100)), c(p(-35), p(-92))),
+        ]);
+    }
+
+    #[test]
+    fn annex_g_division_f32() {
+        check("/", |x, y| x / y, &annex_g_division::<f32>());
+    }
+
+    #[test]
+    fn annex_g_division_f64() {
+        check("/", |x, y| x / y, &annex_g_division::<f64>());
+    }
+
+    #[test]
+    fn annex_g_multiplication_f32() {
+        check("*", |x, y| x * y, &annex_g_multiplication::<f32>());
+    }
+
+    #[test]
+    fn annex_g_multiplication_f64() {
+        check("*", |x, y| x * y, &annex_g_multiplication::<f64>());
+    }
+
+    #[test]
+    fn modulus_f32() {
+        check_modulus(&annex_g_modulus::<f32>());
+        let p = p2_32;
+        check_modulus(&[
+            (c(3.0 * p(124), 4.0 * p(124)), 5.0 * p(124)),
+            (c(3.0 * p(-147), -4.0 * p(-147)), 5.0 * p(-147)),
+        ]);
+    }
+
+    #[test]
+    fn modulus_f64() {
+        check_modulus(&annex_g_modulus::<f64>());
+        let p = p2_64;
+        check_modulus(&[
+            (c(3.0 * p(1020), 4.0 * p(1020)), 5.0 * p(1020)),
+            (c(3.0 * p(-1072), -4.0 * p(-1072)), 5.0 * p(-1072)),
+        ]);
+    }
+}
@@ -0,0 +1,179 @@
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign};
+use super::field::{Semiring, Ring, Field};
+use super::real::Real;
+use crate::{Matrix, Vector};
+
+/// Dual number value + deriv·ε with ε² = 0, for forward-mode automatic
+/// differentiation. Seed one input with `variable` and the others with
+/// `constant`; every result then carries its exact derivative with respect
+/// to that input in `deriv`, through any computation generic over `Field`.
+///
+/// Equality compares both parts, and the ordering is by value first. The
+/// elimination routines pivot on the value, so derivatives are exact
+/// wherever the matrix is nonsingular at the point: a pivot whose value is
+/// zero gives inf or NaN derivatives rather than a wrong finite one.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Dual<T = f32> {
+    pub value: T,
+    pub deriv: T,
+}
+
+impl<T: Real> Dual<T> {
+    pub fn from(value: T, deriv: T) -> Self {
+        Self { value, deriv }
+    }
+
+    /// A quantity that does not depend on the input.
+    pub fn constant(value: T) -> Self {
+        Self::from(value, T::ZERO)
+    }
+
+    /// The input being differentiated with respect to.
+    pub fn variable(value: T) -> Self {
+        Self::from(value, T::ONE)
+    }
+
+    // f(value) with derivative f'(value) by the chain rule.
+    fn chain(self, value: T, slope: T) -> Self {
+        Self::from(value, slope * self.deriv)
+    }
+
+    pub fn sqrt(self) -> Self {
+        let root = self.value.sqrt();
+        self.chain(root, T::ONE / (root + root))
+    }
+
+    pub fn exp(self) -> Self {
+        let e = self.value.exp();
+        self.chain(e, e)
+    }
+
+    pub fn ln(self) -> Self {
+        self.chain(self.value.ln(), T::ONE / self.value)
+    }
+
+    pub fn sin(self) -> Self {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+
+    pub fn cos(self) -> Self {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+
+    pub fn tan(self) -> Self {
+        let c = self.value.cos();
+        self.chain(self.value.sin() / c, T::ONE / (c * c))
+    }
+
+    pub fn powf(self, n: T) -> Self {
+        self.chain(self.value.powf(n), n * self.value.powf(n - T::ONE))
+    }
+}
+
+impl<T: Real> Add for Dual<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::from(self.value + rhs.value, self.deriv + rhs.deriv)
+    }
+}
+
+impl<T: Real> Sub for Dual<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::from(self.value - rhs.value, self.deriv - rhs.deriv)
+    }
+}
+
+impl<T: Real> Mul for Dual<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::from(self.value * rhs.value, self.deriv * rhs.value + self.value * rhs.deriv)
+    }
+}
+
+impl<T: Real> Div for Dual<T> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let q = self.value / rhs.value;
+        Self::from(q, (self.deriv - q * rhs.deriv) / rhs.value)
+    }
+}
+
+impl<T: Real> Neg for Dual<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::from(-self.value, -self.deriv)
+    }
+}
+
+impl<T: Real> AddAssign for Dual<T> {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+impl<T: Real> SubAssign for Dual<T> {
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+impl<T: Real> MulAssign for Dual<T> {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl<T: Real> fmt::Display for Dual<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.deriv < T::ZERO {
+            write!(f, "{}{}ε", self.value, self.deriv)
+        } else {
+            write!(f, "{}+{}ε", self.value, self.deriv)
+        }
+    }
+}
+
+impl<T: Real> From<Dual<T>> for f32 {
+    fn from(x: Dual<T>) -> f32 {
+        x.value.to_f64() as f32
+    }
+}
+
+impl<T: Real> Semiring for Dual<T> {
+    fn zero() -> Self { Self::constant(T::ZERO) }
+    fn one() -> Self { Self::constant(T::ONE) }
+}
+
+impl<T: Real> Ring for Dual<T> {}
+
+impl<T: Real> Field for Dual<T> {
+    fn fma(self, b: Self, c: Self) -> Self { self * b + c }
+    fn abs(self) -> Self {
+        if self.value < T::ZERO { -self } else { self }
+    }
+    fn sqrt(self) -> Self {
+        Dual::sqrt(self)
+    }
+}
+
+/// ∇f at x: one evaluation of f per coordinate, each with that coordinate
+/// seeded as the variable.
+pub fn gradient<T, F>(f: F, x: &Vector<T>) -> Vector<T>
+where
+    T: Real + Semiring,
+    F: Fn(&Vector<Dual<T>>) -> Dual<T>,
+{
+    Vector::from((0..x.size()).map(|j| f(&seeded(x, j)).deriv).collect::<Vec<T>>())
+}
+
+/// The m x n Jacobian of f: R^n -> R^m at x, filled a column per evaluation.
+pub fn jacobian<T, F>(f: F, x: &Vector<T>) -> Matrix<T>
+where
+    T: Real + Semiring,
+    F: Fn(&Vector<Dual<T>>) -> Vector<Dual<T>>,
+{
+    let columns: Vec<Vec<T>> = (0..x.size())
+        .map(|j| f(&seeded(x, j)).data.iter().map(|y| y.deriv).collect())
+        .collect();
+    let rows = columns.first().map_or(0, |c| c.len());
+    Matrix::from((0..rows).map(|i| columns.iter().map(|c| c[i]).collect::<Vec<T>>()).collect::<Vec<_>>())
+}
+
+// x as duals, with coordinate j the variable.
+fn seeded<T: Real + Semiring>(x: &Vector<T>, j: usize) -> Vector<Dual<T>> {
+    Vector::from(x.data.iter().enumerate().map(|(i, &v)| if i == j { Dual::variable(v) } else { Dual::constant(v) }).collect::<Vec<_>>())
+}
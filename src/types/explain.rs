@@ -0,0 +1,174 @@
+use std::fmt;
+use super::field::Field;
+use super::row_ops::{RowOp, RowOpLog};
+use crate::Matrix;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+    Text,
+    Markdown,
+    Latex,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceStep<K: Field> {
+    pub op: RowOp<K>,
+    pub matrix: Matrix<K>,
+}
+
+/// Step-by-step record of an elimination: the starting matrix, every row
+/// operation with the matrix right after it, and a closing summary line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EliminationTrace<K: Field> {
+    pub title: String,
+    pub initial: Matrix<K>,
+    pub steps: Vec<TraceStep<K>>,
+    pub summary: String,
+    pub result: Option<Matrix<K>>,
+}
+
+impl<K: Field + PartialOrd> Matrix<K> {
+    pub fn explain_row_echelon(&self) -> EliminationTrace<K> {
+        let mut m = self.clone();
+        let mut log = RowOpLog::with_snapshots();
+        let result = m.row_echelon_with_log(&mut log);
+        EliminationTrace::from_log("Row echelon form", self, log, "Row echelon form reached".to_string(), Some(result))
+    }
+
+    pub fn explain_rref(&self) -> EliminationTrace<K> {
+        let mut m = self.clone();
+        let mut log = RowOpLog::with_snapshots();
+        let result = m.rref_with_log(&mut log);
+        EliminationTrace::from_log("Reduced row echelon form", self, log, "Reduced row echelon form reached".to_string(), Some(result))
+    }
+
+    pub fn explain_determinant(&self) -> EliminationTrace<K> {
+        let mut m = self.clone();
+        let mut log = RowOpLog::with_snapshots();
+        let det = m.determinant_with_log(&mut log);
+        let swaps = log.ops.iter().filter(|op| matches!(op, RowOp::Swap(..))).count();
+        let summary = if det == K::zero() {
+            format!("det = {}", det)
+        } else {
+            let diagonal: Vec<String> = (0..m.rows).map(|i| format!("{}", m.data[i][i])).collect();
+            format!("det = (-1)^{} · {} = {}", swaps, diagonal.join(" · "), det)
+        };
+        EliminationTrace::from_log("Determinant", self, log, summary, None)
+    }
+
+    pub fn explain_inverse(&self) -> EliminationTrace<K> {
+        let mut m = self.clone();
+        let mut log = RowOpLog::with_snapshots();
+        let initial = Matrix::hstack(&[self, &Matrix::identity(self.rows)]);
+        match m.inverse_with_log(&mut log) {
+            Ok(inv) => EliminationTrace::from_log("Inverse", &initial, log, "A⁻¹ is the right half".to_string(), Some(inv)),
+            Err(e) => EliminationTrace::from_log("Inverse", &initial, log, e, None),
+        }
+    }
+
+    pub fn explain_rank(&self) -> EliminationTrace<K> {
+        let mut m = self.clone();
+        let mut log = RowOpLog::with_snapshots();
+        m.row_echelon_with_log(&mut log);
+        let summary = format!("rank = {} (nonzero rows)", m.nonzero_rows());
+        EliminationTrace::from_log("Rank", self, log, summary, None)
+    }
+}
+
+impl<K: Field + PartialOrd> EliminationTrace<K> {
+    fn from_log(title: &str, initial: &Matrix<K>, log: RowOpLog<K>, summary: String, result: Option<Matrix<K>>) -> Self {
+        let steps = log.ops.into_iter()
+            .zip(log.snapshots)
+            .map(|(op, matrix)| TraceStep { op, matrix })
+            .collect();
+        EliminationTrace { title: title.to_string(), initial: initial.clone(), steps, summary, result }
+    }
+
+    pub fn render(&self, format: TraceFormat) -> String {
+        match format {
+            TraceFormat::Text => self.to_text(),
+            TraceFormat::Markdown => self.to_markdown(),
+            TraceFormat::Latex => self.to_latex(),
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut out = format!("{}\n\n{}", self.title, self.initial);
+        for step in &self.steps {
+            out += &format!("\n{}\n{}", annotate(&step.op, TraceFormat::Text), step.matrix);
+        }
+        out += &format!("\n{}\n", self.summary);
+        if let Some(result) = &self.result {
+            out += &format!("{}", result);
+        }
+        out
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("### {}\n\n{}", self.title, markdown_table(&self.initial));
+        for step in &self.steps {
+            out += &format!("\n**{}**\n\n{}", annotate(&step.op, TraceFormat::Markdown), markdown_table(&step.matrix));
+        }
+        out += &format!("\n{}\n", self.summary);
+        if let Some(result) = &self.result {
+            out += &format!("\n{}", markdown_table(result));
+        }
+        out
+    }
+
+    pub fn to_latex(&self) -> String {
+        let mut out = format!("% {}\n\\[\n{}\n\\]\n", self.title, pmatrix(&self.initial));
+        for step in &self.steps {
+            out += &format!("\\[\n\\xrightarrow{{{}}}\n{}\n\\]\n", annotate(&step.op, TraceFormat::Latex), pmatrix(&step.matrix));
+        }
+        out += &format!("% {}\n", self.summary);
+        if let Some(result) = &self.result {
+            out += &format!("\\[\n{}\n\\]\n", pmatrix(result));
+        }
+        out
+    }
+}
+
+impl<K: Field + PartialOrd> fmt::Display for EliminationTrace<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_text())
+    }
+}
+
+// Negative multipliers are written as subtraction: R2 ← R2 − 0.5·R1.
+fn annotate<K: Field + PartialOrd>(op: &RowOp<K>, format: TraceFormat) -> String {
+    let latex = format == TraceFormat::Latex;
+    let row = |i: usize| if latex { format!("R_{{{}}}", i + 1) } else { format!("R{}", i + 1) };
+    let (arrow, swap, dot, minus, slash) = if latex {
+        (" \\leftarrow ", " \\leftrightarrow ", " \\cdot ", " - ", " / ")
+    } else {
+        (" ← ", " ↔ ", "·", " − ", " / ")
+    };
+
+    match *op {
+        RowOp::Swap(a, b) => format!("{}{}{}", row(a), swap, row(b)),
+        RowOp::Scale(r, ref factor) => format!("{}{}{}{}{}", row(r), arrow, factor, dot, row(r)),
+        RowOp::Divide(r, ref divisor) => format!("{}{}{}{}{}", row(r), arrow, row(r), slash, divisor),
+        RowOp::AddMultiple { target, source, ref factor } => {
+            let (sign, magnitude) = if *factor < K::zero() { (minus, -factor.clone()) } else { (" + ", factor.clone()) };
+            format!("{}{}{}{}{}{}{}", row(target), arrow, row(target), sign, magnitude, dot, row(source))
+        }
+    }
+}
+
+fn markdown_table<K: Field>(m: &Matrix<K>) -> String {
+    let header: Vec<String> = (1..=m.cols).map(|j| format!("C{}", j)).collect();
+    let mut out = format!("| {} |\n|{}\n", header.join(" | "), "---|".repeat(m.cols));
+    for row in &m.data {
+        let cells: Vec<String> = row.iter().map(|v| format!("{}", v)).collect();
+        out += &format!("| {} |\n", cells.join(" | "));
+    }
+    out
+}
+
+fn pmatrix<K: Field>(m: &Matrix<K>) -> String {
+    let rows: Vec<String> = m.data.iter()
+        .map(|row| row.iter().map(|v| format!("{}", v)).collect::<Vec<String>>().join(" & "))
+        .collect();
+    format!("\\begin{{pmatrix}}\n{}\n\\end{{pmatrix}}", rows.join(" \\\\\n"))
+}
@@ -0,0 +1,608 @@
+use std::fmt;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign};
+use super::field::{Semiring, Ring, Field};
+use super::rational::{self, Rational};
+use crate::Matrix;
+
+/// Symbolic expression: a quotient of two polynomials with `Rational`
+/// coefficients in symbols, |e| and sqrt(e). Every result is kept in a
+/// canonical form, so two expressions are equal exactly when their forms
+/// match: numerator and denominator fully expanded with like terms
+/// collected, common monomial factors cancelled, and both scaled to coprime
+/// integer coefficients with the leading one of the denominator positive.
+/// A constant denominator is divided into the numerator instead, leaving
+/// rational coefficients over 1, as in x/2 + 1/3. A common polynomial factor
+/// is cancelled only when one side divides the other, which is always the
+/// case for the exact divisions of Bareiss elimination; there is no general
+/// polynomial gcd.
+///
+/// `Expr` is a reference-counted handle, so clones share their node and it
+/// is freed with the last of them. There is no `PartialOrd`, so the pivoting
+/// routines are unavailable; `Matrix<Expr>` has its own division-free
+/// `determinant`, `adjugate` and `inverse`.
+#[derive(Clone)]
+pub struct Expr(Rc<Fraction>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Fraction {
+    num: Poly,
+    den: Poly,
+}
+
+// Terms in lex order (see `cmp_monomial`), no zero coefficients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Poly {
+    terms: Vec<(Monomial, Rational)>,
+}
+
+// Atoms in increasing order, each with a positive exponent.
+type Monomial = Vec<(Atom, u32)>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Atom {
+    Symbol(Rc<str>),
+    Abs(Expr),
+    Sqrt(Expr),
+}
+
+fn cmp_atom(a: &Atom, b: &Atom) -> Ordering {
+    let rank = |atom: &Atom| match atom {
+        Atom::Symbol(_) => 0,
+        Atom::Abs(_) => 1,
+        Atom::Sqrt(_) => 2,
+    };
+    match (a, b) {
+        (Atom::Symbol(x), Atom::Symbol(y)) => x.cmp(y),
+        (Atom::Abs(x), Atom::Abs(y)) | (Atom::Sqrt(x), Atom::Sqrt(y)) => cmp_fraction(&x.0, &y.0),
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+// Pure lex order with a > b > c ...: `Less` means m comes first, which is
+// also the order terms are printed in, as in a*d - b*c or x^2 - 5*x + 6.
+fn cmp_monomial(m: &Monomial, n: &Monomial) -> Ordering {
+    for ((a, i), (b, j)) in m.iter().zip(n) {
+        match cmp_atom(a, b).then(j.cmp(i)) {
+            Ordering::Equal => continue,
+            other => return other,
+        }
+    }
+    n.len().cmp(&m.len())
+}
+
+fn cmp_poly(p: &Poly, q: &Poly) -> Ordering {
+    for ((m, c), (n, d)) in p.terms.iter().zip(&q.terms) {
+        match cmp_monomial(m, n).then(c.cmp(d)) {
+            Ordering::Equal => continue,
+            other => return other,
+        }
+    }
+    p.terms.len().cmp(&q.terms.len())
+}
+
+fn cmp_fraction(x: &Fraction, y: &Fraction) -> Ordering {
+    cmp_poly(&x.num, &y.num).then_with(|| cmp_poly(&x.den, &y.den))
+}
+
+fn mul_monomial(m: &Monomial, n: &Monomial) -> Monomial {
+    let mut out = Vec::with_capacity(m.len() + n.len());
+    let (mut i, mut j) = (0, 0);
+    while i < m.len() && j < n.len() {
+        match cmp_atom(&m[i].0, &n[j].0) {
+            Ordering::Less => { out.push(m[i].clone()); i += 1; }
+            Ordering::Greater => { out.push(n[j].clone()); j += 1; }
+            Ordering::Equal => { out.push((m[i].0.clone(), m[i].1 + n[j].1)); i += 1; j += 1; }
+        }
+    }
+    out.extend_from_slice(&m[i..]);
+    out.extend_from_slice(&n[j..]);
+    out
+}
+
+// m / n when n divides m.
+fn div_monomial(m: &Monomial, n: &Monomial) -> Option<Monomial> {
+    let mut out = m.clone();
+    for (atom, e) in n {
+        let pos = out.iter().position(|(a, _)| a == atom)?;
+        if out[pos].1 < *e {
+            return None;
+        }
+        out[pos].1 -= e;
+        if out[pos].1 == 0 {
+            out.remove(pos);
+        }
+    }
+    Some(out)
+}
+
+fn gcd_monomial(m: &Monomial, n: &Monomial) -> Monomial {
+    m.iter()
+        .filter_map(|(atom, e)| n.iter().find(|(a, _)| a == atom).map(|(_, f)| (atom.clone(), *e.min(f))))
+        .collect()
+}
+
+impl Poly {
+    fn zero() -> Self {
+        Self { terms: Vec::new() }
+    }
+
+    fn constant(c: Rational) -> Self {
+        Self::from_terms(vec![(Vec::new(), c)])
+    }
+
+    fn one() -> Self {
+        Self::constant(Rational::one())
+    }
+
+    // Sorts, collects like terms and drops zeros.
+    fn from_terms(mut terms: Vec<(Monomial, Rational)>) -> Self {
+        terms.sort_by(|(m, _), (n, _)| cmp_monomial(m, n));
+        let mut out: Vec<(Monomial, Rational)> = Vec::with_capacity(terms.len());
+        for (m, c) in terms {
+            match out.last_mut() {
+                Some((last, d)) if *last == m => *d += c,
+                _ => out.push((m, c)),
+            }
+        }
+        out.retain(|(_, c)| *c != Rational::zero());
+        Self { terms: out }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    fn as_constant(&self) -> Option<Rational> {
+        match self.terms.as_slice() {
+            [] => Some(Rational::zero()),
+            [(m, c)] if m.is_empty() => Some(*c),
+            _ => None,
+        }
+    }
+
+    fn leading(&self) -> Rational {
+        self.terms[0].1
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Self::from_terms(self.terms.iter().chain(&other.terms).cloned().collect())
+    }
+
+    fn neg(&self) -> Self {
+        Self { terms: self.terms.iter().map(|(m, c)| (m.clone(), -*c)).collect() }
+    }
+
+    fn scale(&self, k: Rational) -> Self {
+        Self::from_terms(self.terms.iter().map(|(m, c)| (m.clone(), *c * k)).collect())
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        let mut terms = Vec::with_capacity(self.terms.len() * other.terms.len());
+        for (m, c) in &self.terms {
+            for (n, d) in &other.terms {
+                terms.push((mul_monomial(m, n), *c * *d));
+            }
+        }
+        Self::from_terms(terms)
+    }
+
+    // The monomial dividing every term, with the largest exponents.
+    fn content(&self) -> Monomial {
+        let mut terms = self.terms.iter();
+        let first = terms.next().map_or(Vec::new(), |(m, _)| m.clone());
+        terms.fold(first, |g, (m, _)| gcd_monomial(&g, m))
+    }
+
+    fn div_by_monomial(&self, n: &Monomial) -> Self {
+        Self { terms: self.terms.iter().map(|(m, c)| (div_monomial(m, n).unwrap(), *c)).collect() }
+    }
+
+    // self / d by leading terms, `None` unless the remainder vanishes. In a
+    // monomial order this fails exactly when d does not divide self.
+    fn div_exact(&self, d: &Self) -> Option<Self> {
+        let (lead_mono, lead_coeff) = &d.terms[0];
+        let mut rem = self.clone();
+        let mut quot = Vec::new();
+        while let Some((m, c)) = rem.terms.first() {
+            let q = (div_monomial(m, lead_mono)?, *c / *lead_coeff);
+            rem = rem.add(&d.mul(&Self { terms: vec![(q.0.clone(), -q.1)] }));
+            quot.push(q);
+        }
+        Some(Self::from_terms(quot))
+    }
+}
+
+// n / d with whichever of them divides the other cancelled.
+fn cancel(n: Poly, d: Poly) -> (Poly, Poly) {
+    if let Some(q) = n.div_exact(&d) {
+        (q, Poly::one())
+    } else if let Some(q) = d.div_exact(&n) {
+        (Poly::one(), q)
+    } else {
+        (n, d)
+    }
+}
+
+impl Expr {
+    fn new(num: Poly, den: Poly) -> Self {
+        assert!(!den.is_zero(), "Expr division by zero");
+        if num.is_zero() {
+            return Self::from_parts(Poly::zero(), Poly::one());
+        }
+        let common = gcd_monomial(&num.content(), &den.content());
+        let (num, den) = cancel(num.div_by_monomial(&common), den.div_by_monomial(&common));
+        if let Some(d) = den.as_constant() {
+            return Self::from_parts(num.scale(d.recip()), Poly::one());
+        }
+        // Clear the coefficients' denominators, then their common factor.
+        // The two are coprime, so k is already in lowest terms.
+        let overflow = "Rational overflow in Expr normalization";
+        let coeffs = || num.terms.iter().chain(&den.terms).map(|(_, c)| *c);
+        let lcm = coeffs().fold(1i128, |l, c| {
+            (l / rational::gcd(l, c.denom() as i128)).checked_mul(c.denom() as i128).expect(overflow)
+        });
+        let content = coeffs().fold(0i128, |g, c| {
+            rational::gcd(g, (c.numer() as i128).checked_mul(lcm / c.denom() as i128).expect(overflow))
+        });
+        let sign = if den.leading() < Rational::zero() { -1 } else { 1 };
+        let k = Rational::new(i64::try_from(sign * lcm).expect(overflow), i64::try_from(content).expect(overflow));
+        Self::from_parts(num.scale(k), den.scale(k))
+    }
+
+    fn from_parts(num: Poly, den: Poly) -> Self {
+        Expr(Rc::new(Fraction { num, den }))
+    }
+
+    fn atom(atom: Atom) -> Self {
+        Self::from_parts(Poly { terms: vec![(vec![(atom, 1)], Rational::one())] }, Poly::one())
+    }
+
+    pub fn symbol(name: &str) -> Self {
+        Self::atom(Atom::Symbol(Rc::from(name)))
+    }
+
+    pub fn constant(c: Rational) -> Self {
+        Self::from_parts(Poly::constant(c), Poly::one())
+    }
+
+    /// The value, when no symbols are left.
+    pub fn to_rational(&self) -> Option<Rational> {
+        self.0.den.as_constant().and(self.0.num.as_constant())
+    }
+
+    pub fn numerator(&self) -> Expr {
+        Self::from_parts(self.0.num.clone(), Poly::one())
+    }
+
+    pub fn denominator(&self) -> Expr {
+        Self::from_parts(self.0.den.clone(), Poly::one())
+    }
+
+    /// Every occurrence of the symbol `name` replaced by `value`, simplified.
+    pub fn substitute(&self, name: &str, value: Expr) -> Expr {
+        let poly = |p: &Poly| {
+            p.terms.iter().fold(Expr::zero(), |sum, (m, c)| {
+                sum + m.iter().fold(Expr::constant(*c), |prod, (atom, e)| {
+                    let base = match atom {
+                        Atom::Symbol(s) if &**s == name => value.clone(),
+                        Atom::Symbol(_) => Expr::atom(atom.clone()),
+                        Atom::Abs(x) => Field::abs(x.substitute(name, value.clone())),
+                        Atom::Sqrt(x) => Field::sqrt(x.substitute(name, value.clone())),
+                    };
+                    (0..*e).fold(prod, |acc, _| acc * base.clone())
+                })
+            })
+        };
+        poly(&self.0.num) / poly(&self.0.den)
+    }
+
+    pub fn to_latex(&self) -> String {
+        let Fraction { num, den } = &*self.0;
+        if den.as_constant().is_some() {
+            return poly_string(num, true);
+        }
+        // A lone negative term puts its sign in front of the fraction.
+        match num.terms.as_slice() {
+            [(_, c)] if *c < Rational::zero() => {
+                format!("-\\frac{{{}}}{{{}}}", poly_string(&num.neg(), true), poly_string(den, true))
+            }
+            _ => format!("\\frac{{{}}}{{{}}}", poly_string(num, true), poly_string(den, true)),
+        }
+    }
+}
+
+fn atom_string(atom: &Atom, latex: bool) -> String {
+    match (atom, latex) {
+        (Atom::Symbol(s), _) => s.to_string(),
+        (Atom::Abs(x), false) => format!("|{}|", x),
+        (Atom::Abs(x), true) => format!("\\left|{}\\right|", x.to_latex()),
+        (Atom::Sqrt(x), false) => format!("sqrt({})", x),
+        (Atom::Sqrt(x), true) => format!("\\sqrt{{{}}}", x.to_latex()),
+    }
+}
+
+fn rational_string(c: Rational, latex: bool) -> String {
+    if latex && !c.is_integer() {
+        format!("\\frac{{{}}}{{{}}}", c.numer(), c.denom())
+    } else {
+        c.to_string()
+    }
+}
+
+// Products are a*b in plain text and juxtaposed in LaTeX.
+fn poly_string(p: &Poly, latex: bool) -> String {
+    if p.is_zero() {
+        return "0".to_string();
+    }
+    let (times, open, close) = if latex { (" ", "^{", "}") } else { ("*", "^", "") };
+    let mut out = String::new();
+    for (k, (m, c)) in p.terms.iter().enumerate() {
+        let negative = *c < Rational::zero();
+        out += match (k, negative) {
+            (0, true) => "-",
+            (0, false) => "",
+            (_, true) => " - ",
+            (_, false) => " + ",
+        };
+        let magnitude = if negative { -*c } else { *c };
+        let mut factors: Vec<String> = m.iter()
+            .map(|(atom, e)| {
+                let base = atom_string(atom, latex);
+                if *e == 1 { base } else { format!("{}{}{}{}", base, open, e, close) }
+            })
+            .collect();
+        if factors.is_empty() || magnitude != Rational::one() {
+            factors.insert(0, rational_string(magnitude, latex));
+        }
+        out += &factors.join(times);
+    }
+    out
+}
+
+/// Plain text such as `d/(a*d - b*c)`.
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Fraction { num, den } = &*self.0;
+        if den.as_constant().is_some() {
+            return write!(f, "{}", poly_string(num, false));
+        }
+        let num_text = poly_string(num, false);
+        let den_text = poly_string(den, false);
+        let simple_den = matches!(den.terms.as_slice(), [(m, _)] if m.len() == 1);
+        match (num.terms.len(), simple_den) {
+            (1, true) => write!(f, "{}/{}", num_text, den_text),
+            (1, false) => write!(f, "{}/({})", num_text, den_text),
+            (_, true) => write!(f, "({})/{}", num_text, den_text),
+            (_, false) => write!(f, "({})/({})", num_text, den_text),
+        }
+    }
+}
+
+impl fmt::Debug for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Expr({})", self)
+    }
+}
+
+impl PartialEq for Expr {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0) || self.0 == other.0
+    }
+}
+
+impl Eq for Expr {}
+
+impl Default for Expr {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl From<i64> for Expr {
+    fn from(n: i64) -> Self {
+        Self::constant(Rational::from(n))
+    }
+}
+
+impl From<Rational> for Expr {
+    fn from(c: Rational) -> Self {
+        Self::constant(c)
+    }
+}
+
+/// The value of a constant expression, NaN while symbols are left.
+impl From<Expr> for f32 {
+    fn from(x: Expr) -> f32 {
+        x.to_rational().map_or(f32::NAN, f32::from)
+    }
+}
+
+impl Add for Expr {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let (x, y) = (self.0, rhs.0);
+        if x.den == y.den {
+            Self::new(x.num.add(&y.num), x.den.clone())
+        } else {
+            Self::new(x.num.mul(&y.den).add(&y.num.mul(&x.den)), x.den.mul(&y.den))
+        }
+    }
+}
+
+impl Sub for Expr {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+/// Each numerator is first cancelled against the other denominator.
+impl Mul for Expr {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let (n1, d2) = cancel(self.0.num.clone(), rhs.0.den.clone());
+        let (n2, d1) = cancel(rhs.0.num.clone(), self.0.den.clone());
+        Self::new(n1.mul(&n2), d1.mul(&d2))
+    }
+}
+
+impl Div for Expr {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        assert!(!rhs.0.num.is_zero(), "Expr division by zero");
+        self * Self::from_parts(rhs.0.den.clone(), rhs.0.num.clone())
+    }
+}
+
+impl Neg for Expr {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::from_parts(self.0.num.neg(), self.0.den.clone())
+    }
+}
+
+impl AddAssign for Expr {
+    fn add_assign(&mut self, rhs: Self) { *self = std::mem::take(self) + rhs; }
+}
+impl SubAssign for Expr {
+    fn sub_assign(&mut self, rhs: Self) { *self = std::mem::take(self) - rhs; }
+}
+impl MulAssign for Expr {
+    fn mul_assign(&mut self, rhs: Self) { *self = std::mem::take(self) * rhs; }
+}
+
+impl Semiring for Expr {
+    fn zero() -> Self { Self::constant(Rational::zero()) }
+    fn one() -> Self { Self::constant(Rational::one()) }
+}
+
+impl Ring for Expr {}
+
+impl Field for Expr {
+    fn fma(self, b: Self, c: Self) -> Self {
+        self * b + c
+    }
+
+    /// Evaluated for constants, |e| otherwise.
+    fn abs(self) -> Self {
+        match self.to_rational() {
+            Some(c) => Self::constant(Field::abs(c)),
+            None => Self::atom(Atom::Abs(self)),
+        }
+    }
+
+    /// Exact for squares of rationals, sqrt(e) otherwise.
+    fn sqrt(self) -> Self {
+        let exact = self.to_rational()
+            .and_then(|c| Some(Rational::new(rational::isqrt(c.numer())?, rational::isqrt(c.denom())?)));
+        match exact {
+            Some(r) => Self::constant(r),
+            None => Self::atom(Atom::Sqrt(self)),
+        }
+    }
+}
+
+impl Matrix<Expr> {
+    /// Cofactor expansion along successive rows, with each minor computed
+    /// once: O(n 2^n) products and no divisions at all.
+    pub fn determinant(&self) -> Expr {
+        assert!(self.is_square(), "Error: Matrix must be square to compute determinant!");
+        assert!(self.rows < 64, "Error: Matrix is too large for cofactor expansion!");
+        let mut minors = HashMap::new();
+        minor(&self.data, 0, (1u64 << self.cols) - 1, &mut minors)
+    }
+
+    /// Transpose of the matrix of cofactors, so A adj(A) = det(A) I.
+    pub fn adjugate(&self) -> Matrix<Expr> {
+        assert!(self.is_square(), "Error: Matrix must be square to compute its adjugate!");
+        let n = self.rows;
+        let cofactor = |i: usize, j: usize| {
+            let sub: Vec<Vec<Expr>> = (0..n)
+                .filter(|&r| r != i)
+                .map(|r| (0..n).filter(|&c| c != j).map(|c| self.data[r][c].clone()).collect())
+                .collect();
+            let det = Matrix::from(sub).determinant();
+            if (i + j).is_multiple_of(2) { det } else { -det }
+        };
+        Matrix::from((0..n).map(|i| (0..n).map(|j| cofactor(j, i)).collect::<Vec<Expr>>()).collect::<Vec<_>>())
+    }
+
+    /// adj(A) / det(A), the only division, reduced entry by entry.
+    pub fn inverse(&self) -> Result<Matrix<Expr>, String> {
+        if self.rows != self.cols {
+            return Err("Matrix must be square".to_string());
+        }
+        let det = self.determinant();
+        if det == Expr::zero() {
+            return Err("Matrix is singular".to_string());
+        }
+        let adj = self.adjugate();
+        Ok(Matrix::from(adj.data.iter().map(|row| row.iter().map(|x| x.clone() / det.clone()).collect::<Vec<Expr>>()).collect::<Vec<_>>()))
+    }
+
+    /// A `pmatrix` environment with every entry in LaTeX.
+    pub fn to_latex(&self) -> String {
+        let rows: Vec<String> = self.data.iter()
+            .map(|row| row.iter().map(|v| v.to_latex()).collect::<Vec<String>>().join(" & "))
+            .collect();
+        format!("\\begin{{pmatrix}}\n{}\n\\end{{pmatrix}}", rows.join(" \\\\\n"))
+    }
+}
+
+// Determinant of rows `row..` restricted to the columns in `cols`.
+fn minor(m: &[Vec<Expr>], row: usize, cols: u64, memo: &mut HashMap<u64, Expr>) -> Expr {
+    if cols == 0 {
+        return Expr::one();
+    }
+    if let Some(d) = memo.get(&cols) {
+        return d.clone();
+    }
+    let mut det = Expr::zero();
+    let mut sign = Expr::one();
+    for j in (0..m.len()).filter(|&j| cols & (1 << j) != 0) {
+        if m[row][j] != Expr::zero() {
+            det += sign.clone() * m[row][j].clone() * minor(m, row + 1, cols & !(1 << j), memo);
+        }
+        sign = -sign;
+    }
+    memo.insert(cols, det.clone());
+    det
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn q(n: i64, d: i64) -> Expr {
+        Expr::from(Rational::new(n, d))
+    }
+
+    #[test]
+    fn constant_denominators_divide_into_the_numerator() {
+        let half = q(1, 2) + Expr::from(0);
+        assert_eq!(half, q(1, 2));
+        assert_eq!(half.to_rational(), Some(Rational::new(1, 2)));
+        assert_eq!(half.to_string(), "1/2");
+
+        let x = Expr::symbol("x");
+        let x_half = x.clone() / Expr::from(2);
+        assert_eq!(x_half, q(1, 2) * x.clone());
+        assert_eq!(x_half.to_string(), "1/2*x");
+        assert_eq!(x_half.to_latex(), "\\frac{1}{2} x");
+
+        let affine = q(1, 3) * x.clone() + q(1, 2);
+        assert_eq!(affine, (Expr::from(2) * x + Expr::from(3)) / Expr::from(6));
+        assert_eq!(affine.to_string(), "1/3*x + 1/2");
+    }
+
+    #[test]
+    fn inverse_of_a_constant_diagonal() {
+        let m = Matrix::from([[Expr::from(2), Expr::from(0)], [Expr::from(0), Expr::from(4)]]);
+        let inverse = m.inverse().unwrap();
+        assert_ne!(inverse, Matrix::identity(2));
+        assert_eq!(inverse, Matrix::from([[q(1, 2), Expr::from(0)], [Expr::from(0), q(1, 4)]]));
+    }
+}
@@ -2,38 +2,67 @@ use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign};
 use std::fmt::Display;
 
 
-pub trait Field:
+/// Addition and multiplication with identities, and nothing else: enough for
+/// matrix products, traces and transposes. `zero` must be the additive
+/// identity and annihilate under `*`, `one` the multiplicative identity.
+/// Only `Clone` is required, so heap-backed values such as `BigInt` qualify.
+pub trait Semiring:
     Add<Output = Self>
-    + Sub<Output = Self>
     + Mul<Output = Self>
-    + Div<Output = Self>
-    + Neg<Output = Self>
     + AddAssign
-    + SubAssign
     + MulAssign
-    + Copy
     + Clone
-    + Default
     + PartialEq
     + Display
-    + Into<f32>
-    
 {
     fn zero() -> Self;
     fn one() -> Self;
- 
+
+    /// a1 * b1 + a2 * b2 + ..., the inner loop of `dot`, `mul_vec` and
+    /// `mul_mat`. Types that store less precision than they compute in
+    /// override it to accumulate at the wider precision.
+    fn sum_of_products(pairs: impl Iterator<Item = (Self, Self)>) -> Self {
+        let mut sum = Self::zero();
+        for (a, b) in pairs {
+            sum += a * b;
+        }
+        sum
+    }
+}
+
+/// A semiring with additive inverses.
+pub trait Ring:
+    Semiring
+    + Sub<Output = Self>
+    + Neg<Output = Self>
+    + SubAssign
+{
+}
+
+pub trait Field:
+    Ring
+    + Div<Output = Self>
+    + Default
+    + Into<f32>
+
+{
     fn fma(self, b: Self, c: Self) -> Self;
- 
+
     fn abs(self) -> Self;
- 
+
     fn sqrt(self) -> Self;
 }
 
 
-impl Field for f32{
-
+impl Semiring for f32{
     fn zero() -> Self{0.0}
     fn one() -> Self {1.0}
+}
+
+impl Ring for f32{}
+
+impl Field for f32{
+
     fn fma(self, b: Self, c: Self) -> Self{
         f32::mul_add(self, b, c)
     }
@@ -45,4 +74,31 @@ impl Field for f32{
     fn sqrt(self) -> Self{
         self.powf(0.5)
     }
-}
\ No newline at end of file
+}
+
+// f64 has no `Field` impl, as there is no `Into<f32>` for it, but it can
+// still hold matrices and vectors of results.
+impl Semiring for f64{
+    fn zero() -> Self{0.0}
+    fn one() -> Self {1.0}
+}
+
+impl Ring for f64{}
+
+macro_rules! impl_integer_semiring {
+    ($($t:ty),*) => {$(
+        impl Semiring for $t {
+            fn zero() -> Self { 0 }
+            fn one() -> Self { 1 }
+        }
+    )*};
+}
+
+macro_rules! impl_integer_ring {
+    ($($t:ty),*) => {$(
+        impl Ring for $t {}
+    )*};
+}
+
+impl_integer_semiring!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_integer_ring!(i8, i16, i32, i64, i128, isize);
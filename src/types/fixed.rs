@@ -0,0 +1,353 @@
+use std::fmt;
+use std::cmp::Ordering;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign};
+use super::field::{Semiring, Ring, Field};
+use crate::{Matrix, Vector};
+
+/// Signed fixed-point number in Q(31 - FRAC).FRAC format: an i32 counting
+/// units of 2^-FRAC, so `Fixed<16>` is Q16.16 with range [-32768, 32768) and
+/// resolution 1/65536. Everything is integer arithmetic, for targets without
+/// an FPU.
+///
+/// Results are rounded to nearest, ties away from zero, and saturate at
+/// `MIN`/`MAX` instead of wrapping. Every saturation, division by zero,
+/// square root of a negative number or NaN conversion sets the overflow
+/// flag, which then sticks to everything computed from the value; check it
+/// with `overflowed` at the end. Equality and ordering ignore the flag.
+///
+/// `Fixed<31>` has range [-1, 1) and cannot represent one, so using it where
+/// `Semiring::one` is needed (identities, inverses, `Field` algorithms) is a
+/// compile error; it remains usable for storage and +, -, *.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Fixed<const FRAC: u32> {
+    raw: i32,
+    overflow: bool,
+}
+
+impl<const FRAC: u32> Fixed<FRAC> {
+    const VALID: () = assert!(FRAC < 32, "Fixed: FRAC must be below 32");
+    const HAS_ONE: () = assert!(FRAC < 31, "Fixed: FRAC = 31 cannot represent one");
+
+    pub const MAX: Self = Self { raw: i32::MAX, overflow: false };
+    pub const MIN: Self = Self { raw: i32::MIN, overflow: false };
+    /// The resolution 2^-FRAC.
+    pub const EPSILON: Self = Self { raw: 1, overflow: false };
+
+    pub fn from_raw(raw: i32) -> Self {
+        Self::saturate(raw as i128, false)
+    }
+
+    pub fn from_int(n: i32) -> Self {
+        Self::saturate((n as i128) << FRAC, false)
+    }
+
+    /// Nearest representable value, saturating; NaN gives zero.
+    pub fn from_f32(x: f32) -> Self {
+        if x.is_nan() {
+            return Self::saturate(0, true);
+        }
+        let scaled = (x as f64 * (1u64 << FRAC) as f64).round();
+        if scaled > i32::MAX as f64 {
+            Self::saturate(i32::MAX as i128 + 1, false)
+        } else if scaled < i32::MIN as f64 {
+            Self::saturate(i32::MIN as i128 - 1, false)
+        } else {
+            Self::saturate(scaled as i128, false)
+        }
+    }
+
+    pub fn raw(self) -> i32 {
+        self.raw
+    }
+
+    /// Whether this value or any it was computed from saturated.
+    pub fn overflowed(self) -> bool {
+        self.overflow
+    }
+
+    /// Exact, as every Q-format value with FRAC < 32 fits in an f64.
+    pub fn to_f64(self) -> f64 {
+        self.raw as f64 / (1u64 << FRAC) as f64
+    }
+
+    pub fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+
+    // Clamps a wide intermediate into i32, flagging when it does not fit.
+    fn saturate(raw: i128, overflow: bool) -> Self {
+        let () = Self::VALID;
+        match i32::try_from(raw) {
+            Ok(raw) => Self { raw, overflow },
+            Err(_) => Self { raw: if raw < 0 { i32::MIN } else { i32::MAX }, overflow: true },
+        }
+    }
+
+    fn flags(self, rhs: Self) -> bool {
+        self.overflow || rhs.overflow
+    }
+
+    /// u + t (v - u) with t in fixed point as well, rounded once; `lerp`
+    /// would take t as an f32.
+    pub fn lerp(u: Self, v: Self, t: Self) -> Self {
+        t.fma(v - u, u)
+    }
+}
+
+// x / 2^shift rounded to nearest, ties away from zero.
+fn round_shift(x: i128, shift: u32) -> i128 {
+    if shift == 0 {
+        return x;
+    }
+    let half = 1i128 << (shift - 1);
+    if x >= 0 { (x + half) >> shift } else { -((half - x) >> shift) }
+}
+
+// n / d rounded to nearest, ties away from zero; d != 0.
+fn round_div(n: i128, d: i128) -> i128 {
+    let (q, r) = (n / d, n % d);
+    if 2 * r.abs() >= d.abs() {
+        q + if (n < 0) == (d < 0) { 1 } else { -1 }
+    } else {
+        q
+    }
+}
+
+impl<const FRAC: u32> Add for Fixed<FRAC> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::saturate(self.raw as i128 + rhs.raw as i128, self.flags(rhs))
+    }
+}
+
+impl<const FRAC: u32> Sub for Fixed<FRAC> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::saturate(self.raw as i128 - rhs.raw as i128, self.flags(rhs))
+    }
+}
+
+impl<const FRAC: u32> Mul for Fixed<FRAC> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::saturate(round_shift(self.raw as i128 * rhs.raw as i128, FRAC), self.flags(rhs))
+    }
+}
+
+/// Division by zero saturates towards the sign of the dividend, and 0 / 0
+/// is zero; both set the overflow flag.
+impl<const FRAC: u32> Div for Fixed<FRAC> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let n = (self.raw as i128) << FRAC;
+        if rhs.raw == 0 {
+            return Self::saturate(n.signum() << 64, true);
+        }
+        Self::saturate(round_div(n, rhs.raw as i128), self.flags(rhs))
+    }
+}
+
+impl<const FRAC: u32> Neg for Fixed<FRAC> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::saturate(-(self.raw as i128), self.overflow)
+    }
+}
+
+impl<const FRAC: u32> AddAssign for Fixed<FRAC> {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+impl<const FRAC: u32> SubAssign for Fixed<FRAC> {
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+impl<const FRAC: u32> MulAssign for Fixed<FRAC> {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl<const FRAC: u32> PartialEq for Fixed<FRAC> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl<const FRAC: u32> Eq for Fixed<FRAC> {}
+
+impl<const FRAC: u32> PartialOrd for Fixed<FRAC> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const FRAC: u32> Ord for Fixed<FRAC> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl<const FRAC: u32> fmt::Display for Fixed<FRAC> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_f64())
+    }
+}
+
+impl<const FRAC: u32> From<f32> for Fixed<FRAC> {
+    fn from(x: f32) -> Self {
+        Self::from_f32(x)
+    }
+}
+
+impl<const FRAC: u32> From<Fixed<FRAC>> for f32 {
+    fn from(x: Fixed<FRAC>) -> f32 {
+        x.to_f32()
+    }
+}
+
+impl<const FRAC: u32> Semiring for Fixed<FRAC> {
+    fn zero() -> Self { Self::from_raw(0) }
+    fn one() -> Self {
+        let () = Self::HAS_ONE;
+        Self::from_int(1)
+    }
+}
+
+impl<const FRAC: u32> Ring for Fixed<FRAC> {}
+
+impl<const FRAC: u32> Field for Fixed<FRAC> {
+    /// Rounded once, after the exact sum.
+    fn fma(self, b: Self, c: Self) -> Self {
+        let exact = self.raw as i128 * b.raw as i128 + ((c.raw as i128) << FRAC);
+        Self::saturate(round_shift(exact, FRAC), self.flags(b) || c.overflow)
+    }
+
+    fn abs(self) -> Self {
+        Self::saturate((self.raw as i128).abs(), self.overflow)
+    }
+
+    /// Integer square root of raw * 2^FRAC, rounded to nearest; negative
+    /// inputs give zero with the overflow flag set.
+    fn sqrt(self) -> Self {
+        if self.raw < 0 {
+            return Self::saturate(0, true);
+        }
+        let n = (self.raw as u64) << FRAC;
+        let r = n.isqrt();
+        let rounded = if n - r * r > r { r + 1 } else { r };
+        Self::saturate(rounded as i128, self.overflow)
+    }
+}
+
+/// How far a fixed-point result is from the f32 computation of the same
+/// operation. `max_ulps` is `max_abs` in units of the resolution 2^-FRAC,
+/// and `overflowed` is set if any entry saturated along the way.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedError {
+    pub max_abs: f32,
+    pub rms: f32,
+    pub max_ulps: f32,
+    pub overflowed: bool,
+}
+
+impl FixedError {
+    fn measure<const FRAC: u32>(pairs: impl Iterator<Item = (Fixed<FRAC>, f32)>) -> Self {
+        let (mut max_abs, mut sum_sq, mut count, mut overflowed) = (0.0f64, 0.0f64, 0usize, false);
+        for (fixed, reference) in pairs {
+            let err = (fixed.to_f64() - reference as f64).abs();
+            max_abs = max_abs.max(err);
+            sum_sq += err * err;
+            count += 1;
+            overflowed |= fixed.overflowed();
+        }
+        let rms = if count == 0 { 0.0 } else { (sum_sq / count as f64).sqrt() };
+        Self {
+            max_abs: max_abs as f32,
+            rms: rms as f32,
+            max_ulps: (max_abs * (1u64 << FRAC) as f64) as f32,
+            overflowed,
+        }
+    }
+}
+
+impl Matrix<f32> {
+    /// Every entry rounded to the nearest `Fixed<FRAC>`, saturating.
+    pub fn to_fixed<const FRAC: u32>(&self) -> Matrix<Fixed<FRAC>> {
+        Matrix::from(
+            self.data.iter()
+                .map(|row| row.iter().map(|&v| Fixed::from_f32(v)).collect::<Vec<Fixed<FRAC>>>())
+                .collect::<Vec<Vec<Fixed<FRAC>>>>()
+        )
+    }
+}
+
+impl<const FRAC: u32> Matrix<Fixed<FRAC>> {
+    pub fn to_f32(&self) -> Matrix<f32> {
+        Matrix::from(
+            self.data.iter()
+                .map(|row| row.iter().map(|&v| v.to_f32()).collect::<Vec<f32>>())
+                .collect::<Vec<Vec<f32>>>()
+        )
+    }
+
+    /// Whether any entry saturated.
+    pub fn overflowed(&self) -> bool {
+        self.data.iter().flatten().any(|v| v.overflowed())
+    }
+
+    /// Error against `reference`, the same operation done in f32.
+    pub fn error_against(&self, reference: &Matrix<f32>) -> FixedError {
+        assert!(self.rows == reference.rows && self.cols == reference.cols, "Error: Matrices must have the same dimensions to compare them!");
+        FixedError::measure(self.data.iter().flatten().copied().zip(reference.data.iter().flatten().copied()))
+    }
+
+    /// `Fixed::lerp` entry by entry, with t in fixed point.
+    pub fn lerp_fixed(u: Self, v: Self, t: Fixed<FRAC>) -> Self {
+        assert!(u.rows == v.rows && u.cols == v.cols, "Error: Matrices must have the same dimensions to interpolate!");
+        Matrix::from(
+            u.data.iter().zip(&v.data)
+                .map(|(a, b)| a.iter().zip(b).map(|(&x, &y)| Fixed::lerp(x, y, t)).collect::<Vec<Fixed<FRAC>>>())
+                .collect::<Vec<Vec<Fixed<FRAC>>>>()
+        )
+    }
+}
+
+/// `projection` without floating point: the field of view comes in as
+/// cot(fov / 2), e.g. from a table, and everything else is fixed-point.
+pub fn projection_fixed<const FRAC: u32>(cot_half_fov: Fixed<FRAC>, ratio: Fixed<FRAC>, near: Fixed<FRAC>, far: Fixed<FRAC>) -> Matrix<Fixed<FRAC>> {
+    let zero = Fixed::zero();
+    let depth = near - far;
+    Matrix::from(vec![
+        vec![cot_half_fov / ratio, zero,         zero,                zero],
+        vec![zero,                 cot_half_fov, zero,                zero],
+        vec![zero,                 zero,         far / depth,         -Fixed::one()],
+        vec![zero,                 zero,         near * far / depth,  zero],
+    ])
+}
+
+impl Vector<f32> {
+    /// Every entry rounded to the nearest `Fixed<FRAC>`, saturating.
+    pub fn to_fixed<const FRAC: u32>(&self) -> Vector<Fixed<FRAC>> {
+        Vector::from(self.data.iter().map(|&v| Fixed::from_f32(v)).collect::<Vec<Fixed<FRAC>>>())
+    }
+}
+
+impl<const FRAC: u32> Vector<Fixed<FRAC>> {
+    pub fn to_f32(&self) -> Vector<f32> {
+        Vector::from(self.data.iter().map(|&v| v.to_f32()).collect::<Vec<f32>>())
+    }
+
+    /// Whether any entry saturated.
+    pub fn overflowed(&self) -> bool {
+        self.data.iter().any(|v| v.overflowed())
+    }
+
+    /// Error against `reference`, the same operation done in f32.
+    pub fn error_against(&self, reference: &Vector<f32>) -> FixedError {
+        assert_eq!(self.size(), reference.size(), "Vector size mismatch: {} vs {}", self.size(), reference.size());
+        FixedError::measure(self.data.iter().copied().zip(reference.data.iter().copied()))
+    }
+
+    /// `Fixed::lerp` entry by entry, with t in fixed point.
+    pub fn lerp_fixed(u: Self, v: Self, t: Fixed<FRAC>) -> Self {
+        u.assert_same_size(&v);
+        Vector::from(u.data.iter().zip(&v.data).map(|(&x, &y)| Fixed::lerp(x, y, t)).collect::<Vec<Fixed<FRAC>>>())
+    }
+}
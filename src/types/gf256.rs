@@ -0,0 +1,172 @@
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign};
+use super::field::{Semiring, Ring, Field};
+
+/// Element of GF(2^8) = GF(2)[x] / (POLY), where POLY is a primitive polynomial
+/// of degree 8 written as a bit mask. The default 0x11d is
+/// x^8 + x^4 + x^3 + x^2 + 1, the usual choice for Reed-Solomon and RAID-6;
+/// AES uses 0x11b, which is irreducible but not primitive and is rejected.
+///
+/// Addition is xor. Multiplication and division go through log/antilog
+/// tables built at compile time for each POLY, and a polynomial that is not
+/// primitive fails that build.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Gf256<const POLY: u16 = 0x11d>(pub u8);
+
+struct Tables {
+    // exp is doubled so that exp[log a + log b] never needs reducing mod 255.
+    exp: [u8; 512],
+    log: [u8; 256],
+}
+
+const fn build_tables(poly: u16) -> Tables {
+    assert!(poly >= 0x100 && poly <= 0x1ff, "GF(2^8) polynomial must have degree 8");
+    let mut exp = [0u8; 512];
+    let mut log = [0u8; 256];
+    let mut x: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        assert!(x != 0 && (i == 0 || x != 1), "GF(2^8) polynomial is not primitive");
+        exp[i] = x as u8;
+        log[x as usize] = i as u8;
+        x <<= 1;
+        if x & 0x100 != 0 {
+            x ^= poly;
+        }
+        i += 1;
+    }
+    assert!(x == 1, "GF(2^8) polynomial is not primitive");
+    while i < 512 {
+        exp[i] = exp[i - 255];
+        i += 1;
+    }
+    Tables { exp, log }
+}
+
+impl<const POLY: u16> Gf256<POLY> {
+    const TABLES: Tables = build_tables(POLY);
+
+    fn tables() -> &'static Tables {
+        &Self::TABLES
+    }
+
+    pub fn new(value: u8) -> Self {
+        Gf256(value)
+    }
+
+    pub fn value(self) -> u8 {
+        self.0
+    }
+
+    /// α^i for the generator α = x.
+    pub fn exp(i: usize) -> Self {
+        Gf256(Self::tables().exp[i % 255])
+    }
+
+    /// Discrete log base α, `None` for zero.
+    pub fn log(self) -> Option<usize> {
+        if self.0 == 0 { None } else { Some(Self::tables().log[self.0 as usize] as usize) }
+    }
+
+    pub fn pow(self, exp: usize) -> Self {
+        match self.log() {
+            None => if exp == 0 { Self::one() } else { self },
+            Some(l) => Self::exp(l * exp % 255),
+        }
+    }
+
+    pub fn inverse(self) -> Option<Self> {
+        self.log().map(|l| Self::exp(255 - l))
+    }
+}
+
+impl<const POLY: u16> From<u8> for Gf256<POLY> {
+    fn from(value: u8) -> Self {
+        Gf256(value)
+    }
+}
+
+impl<const POLY: u16> From<Gf256<POLY>> for f32 {
+    fn from(x: Gf256<POLY>) -> f32 {
+        x.0 as f32
+    }
+}
+
+impl<const POLY: u16> Add for Gf256<POLY> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        Gf256(self.0 ^ rhs.0)
+    }
+}
+
+// Characteristic 2: subtraction is addition.
+impl<const POLY: u16> Sub for Gf256<POLY> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        Gf256(self.0 ^ rhs.0)
+    }
+}
+
+impl<const POLY: u16> Mul for Gf256<POLY> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        match (self.log(), rhs.log()) {
+            (Some(a), Some(b)) => Gf256(Self::tables().exp[a + b]),
+            _ => Gf256(0),
+        }
+    }
+}
+
+impl<const POLY: u16> Div for Gf256<POLY> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let b = rhs.log().expect("GF(2^8) division by zero");
+        match self.log() {
+            Some(a) => Gf256(Self::tables().exp[a + 255 - b]),
+            None => Gf256(0),
+        }
+    }
+}
+
+impl<const POLY: u16> Neg for Gf256<POLY> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl<const POLY: u16> AddAssign for Gf256<POLY> {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+impl<const POLY: u16> SubAssign for Gf256<POLY> {
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+impl<const POLY: u16> MulAssign for Gf256<POLY> {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl<const POLY: u16> fmt::Display for Gf256<POLY> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const POLY: u16> Semiring for Gf256<POLY> {
+    fn zero() -> Self { Gf256(0) }
+    fn one() -> Self { Gf256(1) }
+}
+
+impl<const POLY: u16> Ring for Gf256<POLY> {}
+
+impl<const POLY: u16> Field for Gf256<POLY> {
+    fn fma(self, b: Self, c: Self) -> Self { self * b + c }
+    fn abs(self) -> Self { self }
+    // Squaring is a bijection in characteristic 2, so every element has
+    // exactly one square root: x^(2^7).
+    fn sqrt(self) -> Self {
+        self.pow(128)
+    }
+}
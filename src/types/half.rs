@@ -0,0 +1,310 @@
+use std::fmt;
+use std::cmp::Ordering;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign};
+use super::field::{Semiring, Ring, Field};
+use crate::{Matrix, Vector};
+
+/// IEEE 754 binary16: 1 sign, 5 exponent and 10 fraction bits, so 11 bits
+/// of precision over roughly [6e-8, 65504].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct F16 {
+    bits: u16,
+}
+
+/// bfloat16: the top half of an f32, with its full 8-bit exponent range but
+/// only 8 bits of precision.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BF16 {
+    bits: u16,
+}
+
+// f32 -> binary16, rounding to nearest even. Overflow gives infinity, and
+// NaN stays a (quiet) NaN.
+fn f16_from_f32(x: f32) -> u16 {
+    let bits = x.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exp = ((bits >> 23) & 0xff) as i32;
+    let man = bits & 0x7f_ffff;
+
+    if exp == 0xff {
+        return sign | 0x7c00 | if man != 0 { 0x0200 | (man >> 13) as u16 } else { 0 };
+    }
+    let e = exp - 127 + 15;
+    if e >= 0x1f {
+        return sign | 0x7c00;
+    }
+    if e <= 0 {
+        // Subnormal result, in units of 2^-24; rounding up into the
+        // smallest normal gives its encoding directly.
+        if e < -10 {
+            return sign;
+        }
+        return sign | round_shift(man | 0x80_0000, (14 - e) as u32) as u16;
+    }
+    // A carry out of the fraction bumps the exponent, up to infinity.
+    let truncated = ((e as u32) << 10) | (man >> 13);
+    sign | (truncated + round_up(truncated, man & 0x1fff, 0x1000)) as u16
+}
+
+fn f16_to_f32(h: u16) -> f32 {
+    let sign = ((h & 0x8000) as u32) << 16;
+    let exp = ((h >> 10) & 0x1f) as u32;
+    let man = (h & 0x3ff) as u32;
+    match exp {
+        0 => {
+            let magnitude = man as f32 * f32::from_bits(0x3380_0000);
+            f32::from_bits(sign | magnitude.to_bits())
+        }
+        0x1f => f32::from_bits(sign | 0x7f80_0000 | (man << 13)),
+        _ => f32::from_bits(sign | ((exp + 112) << 23) | (man << 13)),
+    }
+}
+
+// f32 -> bfloat16, rounding to nearest even; NaN is kept quiet.
+fn bf16_from_f32(x: f32) -> u16 {
+    let bits = x.to_bits();
+    if x.is_nan() {
+        return (bits >> 16) as u16 | 0x0040;
+    }
+    let truncated = bits >> 16;
+    (truncated + round_up(truncated, bits & 0xffff, 0x8000)) as u16
+}
+
+fn bf16_to_f32(b: u16) -> f32 {
+    f32::from_bits((b as u32) << 16)
+}
+
+// 1 if a value truncated to `kept`, with dropped bits `rest` out of a unit
+// of 2 * `half`, rounds up to nearest even.
+fn round_up(kept: u32, rest: u32, half: u32) -> u32 {
+    (rest > half || (rest == half && kept & 1 == 1)) as u32
+}
+
+// m / 2^shift rounded to nearest even, for 0 < shift < 32.
+fn round_shift(m: u32, shift: u32) -> u32 {
+    let kept = m >> shift;
+    kept + round_up(kept, m & ((1 << shift) - 1), 1 << (shift - 1))
+}
+
+/// Storage formats with arithmetic done in f32 and rounded back, which is
+/// correctly rounded for +, -, *, / and sqrt since f32 carries at least
+/// 2p + 2 bits for their precision p.
+///
+/// `dot`, `mul_vec` and `mul_mat` accumulate in f32 and round once per
+/// entry, so a 4096-term sum of ones is 4096 rather than stalling where the
+/// format runs out of precision: at 2048 for F16, at 256 for BF16. Other
+/// running sums, such as `trace` or a loop of `+=`, round every step. The
+/// `_f32` products skip even the final rounding.
+///
+/// Each product is formed in f32 before the sum. For F16 that is exact, as
+/// 2p = 22 bits fit in f32's 24 and the exponent range is far narrower. BF16
+/// shares f32's exponent range, so its 16-bit products are exact only while
+/// they stay in range: they can overflow to infinity or underflow to a
+/// subnormal or zero.
+macro_rules! impl_half {
+    ($t:ident, $encode:ident, $decode:ident, $to:ident, $max:expr, $min_positive:expr, $epsilon:expr, $infinity:expr, $nan:expr) => {
+        impl $t {
+            pub const MAX: Self = Self { bits: $max };
+            pub const MIN_POSITIVE: Self = Self { bits: $min_positive };
+            pub const EPSILON: Self = Self { bits: $epsilon };
+            pub const INFINITY: Self = Self { bits: $infinity };
+            pub const NAN: Self = Self { bits: $nan };
+
+            pub fn from_bits(bits: u16) -> Self {
+                Self { bits }
+            }
+
+            pub fn to_bits(self) -> u16 {
+                self.bits
+            }
+
+            /// Rounded to nearest, ties to even.
+            pub fn from_f32(x: f32) -> Self {
+                Self { bits: $encode(x) }
+            }
+
+            /// Exact.
+            pub fn to_f32(self) -> f32 {
+                $decode(self.bits)
+            }
+
+            pub fn is_nan(self) -> bool {
+                self.to_f32().is_nan()
+            }
+
+            fn map(self, f: impl Fn(f32) -> f32) -> Self {
+                Self::from_f32(f(self.to_f32()))
+            }
+
+            fn zip(self, rhs: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+                Self::from_f32(f(self.to_f32(), rhs.to_f32()))
+            }
+        }
+
+        impl Add for $t {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self { self.zip(rhs, |a, b| a + b) }
+        }
+
+        impl Sub for $t {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self { self.zip(rhs, |a, b| a - b) }
+        }
+
+        impl Mul for $t {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self { self.zip(rhs, |a, b| a * b) }
+        }
+
+        impl Div for $t {
+            type Output = Self;
+            fn div(self, rhs: Self) -> Self { self.zip(rhs, |a, b| a / b) }
+        }
+
+        impl Neg for $t {
+            type Output = Self;
+            fn neg(self) -> Self { Self { bits: self.bits ^ 0x8000 } }
+        }
+
+        impl AddAssign for $t {
+            fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+        }
+        impl SubAssign for $t {
+            fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+        }
+        impl MulAssign for $t {
+            fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+        }
+
+        /// As floats: -0 equals 0 and NaN equals nothing.
+        impl PartialEq for $t {
+            fn eq(&self, other: &Self) -> bool {
+                self.to_f32() == other.to_f32()
+            }
+        }
+
+        impl PartialOrd for $t {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.to_f32().partial_cmp(&other.to_f32())
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.to_f32())
+            }
+        }
+
+        impl From<f32> for $t {
+            fn from(x: f32) -> Self {
+                Self::from_f32(x)
+            }
+        }
+
+        impl From<$t> for f32 {
+            fn from(x: $t) -> f32 {
+                x.to_f32()
+            }
+        }
+
+        impl Semiring for $t {
+            fn zero() -> Self { Self::from_bits(0) }
+            fn one() -> Self { Self::from_f32(1.0) }
+
+            /// Products summed in f32, rounded once. The products are exact
+            /// for F16, and for BF16 while they stay within f32's range.
+            fn sum_of_products(pairs: impl Iterator<Item = (Self, Self)>) -> Self {
+                Self::from_f32(pairs.map(|(a, b)| a.to_f32() * b.to_f32()).sum())
+            }
+        }
+
+        impl Ring for $t {}
+
+        impl Field for $t {
+            /// The f32 fma, rounded to the format.
+            fn fma(self, b: Self, c: Self) -> Self {
+                Self::from_f32(self.to_f32().mul_add(b.to_f32(), c.to_f32()))
+            }
+            fn abs(self) -> Self {
+                Self { bits: self.bits & 0x7fff }
+            }
+            fn sqrt(self) -> Self {
+                self.map(f32::sqrt)
+            }
+        }
+
+        impl Matrix<f32> {
+            /// Every entry rounded to nearest even, at half the memory.
+            pub fn $to(&self) -> Matrix<$t> {
+                Matrix::from(
+                    self.data.iter()
+                        .map(|row| row.iter().map(|&v| $t::from_f32(v)).collect::<Vec<$t>>())
+                        .collect::<Vec<Vec<$t>>>()
+                )
+            }
+        }
+
+        impl Vector<f32> {
+            /// Every entry rounded to nearest even, at half the memory.
+            pub fn $to(&self) -> Vector<$t> {
+                Vector::from(self.data.iter().map(|&v| $t::from_f32(v)).collect::<Vec<$t>>())
+            }
+        }
+
+        impl Vector<$t> {
+            pub fn to_f32(&self) -> Vector<f32> {
+                Vector::from(self.data.iter().map(|&v| v.to_f32()).collect::<Vec<f32>>())
+            }
+
+            /// `dot`, accumulated in f32.
+            pub fn dot_f32(&self, v: &Vector<$t>) -> f32 {
+                self.assert_same_size(v);
+                self.data.iter().zip(&v.data).map(|(a, b)| a.to_f32() * b.to_f32()).sum()
+            }
+        }
+
+        impl Matrix<$t> {
+            pub fn to_f32(&self) -> Matrix<f32> {
+                Matrix::from(
+                    self.data.iter()
+                        .map(|row| row.iter().map(|&v| v.to_f32()).collect::<Vec<f32>>())
+                        .collect::<Vec<Vec<f32>>>()
+                )
+            }
+
+            /// `mul_vec`, accumulated in f32.
+            pub fn mul_vec_f32(&self, vec: &Vector<$t>) -> Vector<f32> {
+                self.mul_vec_mixed(&vec.to_f32())
+            }
+
+            /// Half-precision weights times an f32 vector, accumulated in f32:
+            /// only the matrix is stored compactly.
+            pub fn mul_vec_mixed(&self, vec: &Vector<f32>) -> Vector<f32> {
+                assert_eq!(self.cols, vec.size(), "Error: The number of columns in A must equal the size of the vector!");
+                Vector::from(
+                    self.data.iter()
+                        .map(|row| row.iter().zip(&vec.data).map(|(a, &x)| a.to_f32() * x).sum())
+                        .collect::<Vec<f32>>()
+                )
+            }
+
+            /// `mul_mat`, accumulated in f32.
+            pub fn mul_mat_f32(&self, other: &Matrix<$t>) -> Matrix<f32> {
+                assert_eq!(self.cols, other.rows, "Error: The number of columns in A must equal the number of rows in B!");
+                let other = other.to_f32();
+                Matrix::from(
+                    self.data.iter()
+                        .map(|row| {
+                            (0..other.cols)
+                                .map(|j| row.iter().zip(&other.data).map(|(a, b_row)| a.to_f32() * b_row[j]).sum())
+                                .collect::<Vec<f32>>()
+                        })
+                        .collect::<Vec<Vec<f32>>>()
+                )
+            }
+        }
+    };
+}
+
+impl_half!(F16, f16_from_f32, f16_to_f32, to_f16, 0x7bff, 0x0400, 0x1400, 0x7c00, 0x7e00);
+impl_half!(BF16, bf16_from_f32, bf16_to_f32, to_bf16, 0x7f7f, 0x0080, 0x3c00, 0x7f80, 0x7fc0);
@@ -0,0 +1,348 @@
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign};
+use super::field::{Semiring, Ring, Field};
+use crate::{Matrix, Vector};
+
+// Below this magnitude a product or quotient may have underflowed, and the
+// FMA residual that proves it exact can no longer be trusted.
+const EXACT_RESIDUAL_MIN: f64 = 1e-275;
+
+// Rounds of epsilon inflation `verify_solution` tries before giving up.
+const MAX_INFLATIONS: usize = 10;
+
+/// Closed interval [lo, hi] of f64, for rigorous enclosures: every operation
+/// returns an interval containing every result of the operation on members
+/// of its operands. Endpoints are rounded outward, and only by the one ulp
+/// that round-to-nearest can be off, in the direction the error-free
+/// residual shows the exact value lies; exact results stay points.
+///
+/// Dividing by an interval that contains zero gives the whole real line
+/// rather than panicking, so elimination on a matrix whose singularity
+/// cannot be ruled out ends in infinite, but still correct, bounds.
+///
+/// The ordering is lexicographic on (lo, hi), not an interval order. With
+/// `abs` returning [mignitude, magnitude] it makes elimination pivot on the
+/// entry farthest from zero.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Interval {
+    lo: f64,
+    hi: f64,
+}
+
+impl Interval {
+    pub fn new(lo: f64, hi: f64) -> Self {
+        assert!(lo <= hi, "Interval bounds out of order: [{}, {}]", lo, hi);
+        Self { lo, hi }
+    }
+
+    pub fn point(x: f64) -> Self {
+        Self::new(x, x)
+    }
+
+    /// (-inf, inf).
+    pub fn entire() -> Self {
+        Self::new(f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    pub fn mid(&self) -> f64 {
+        if self.lo.is_infinite() || self.hi.is_infinite() {
+            return if self.lo == -self.hi { 0.0 } else { self.lo + self.hi };
+        }
+        self.lo / 2.0 + self.hi / 2.0
+    }
+
+    /// hi - lo, rounded up.
+    pub fn width(&self) -> f64 {
+        sub_up(self.hi, self.lo)
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Whether `other` lies strictly inside `self`.
+    pub fn interior_contains(&self, other: &Self) -> bool {
+        self.lo < other.lo && other.hi < self.hi
+    }
+
+    /// The smallest interval containing both.
+    pub fn hull(&self, other: &Self) -> Self {
+        Self::new(self.lo.min(other.lo), self.hi.max(other.hi))
+    }
+
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let (lo, hi) = (self.lo.max(other.lo), self.hi.min(other.hi));
+        if lo <= hi { Some(Self::new(lo, hi)) } else { None }
+    }
+}
+
+// TwoSum: the exact error of a + b as a float, NaN if the sum overflowed.
+fn sum_error(a: f64, b: f64, s: f64) -> f64 {
+    let bb = s - a;
+    (a - (s - bb)) + (b - bb)
+}
+
+fn product_error(a: f64, b: f64, p: f64) -> f64 {
+    if a == 0.0 || b == 0.0 {
+        return 0.0;
+    }
+    if p.abs() < EXACT_RESIDUAL_MIN {
+        return f64::NAN;
+    }
+    a.mul_add(b, -p)
+}
+
+// The sign of a / b - q, from the exact remainder a - q b.
+fn quotient_error(a: f64, b: f64, q: f64) -> f64 {
+    if a == 0.0 {
+        return 0.0;
+    }
+    if q.abs() < EXACT_RESIDUAL_MIN || a.abs() < EXACT_RESIDUAL_MIN {
+        return f64::NAN;
+    }
+    (-q).mul_add(b, a) * b.signum()
+}
+
+// Lower and upper bounds for an exact value approx + error, where error is
+// known exactly, or NaN when it is not.
+fn round_down(approx: f64, error: f64) -> f64 {
+    if error >= 0.0 { approx } else { approx.next_down() }
+}
+
+fn round_up(approx: f64, error: f64) -> f64 {
+    if error <= 0.0 { approx } else { approx.next_up() }
+}
+
+fn add_down(a: f64, b: f64) -> f64 {
+    let s = a + b;
+    if a.is_infinite() || b.is_infinite() { s } else { round_down(s, sum_error(a, b, s)) }
+}
+
+fn add_up(a: f64, b: f64) -> f64 {
+    let s = a + b;
+    if a.is_infinite() || b.is_infinite() { s } else { round_up(s, sum_error(a, b, s)) }
+}
+
+fn sub_up(a: f64, b: f64) -> f64 {
+    add_up(a, -b)
+}
+
+// Endpoint product, with 0 * inf = 0: an unbounded interval times one that
+// is exactly zero is zero.
+fn mul_bounds(a: f64, b: f64) -> (f64, f64) {
+    if a == 0.0 || b == 0.0 {
+        return (0.0, 0.0);
+    }
+    let p = a * b;
+    if a.is_infinite() || b.is_infinite() {
+        return (p, p);
+    }
+    let error = product_error(a, b, p);
+    (round_down(p, error), round_up(p, error))
+}
+
+fn div_bounds(a: f64, b: f64) -> (f64, f64) {
+    let q = a / b;
+    if a.is_infinite() || b.is_infinite() {
+        return if q.is_nan() { (f64::NEG_INFINITY, f64::INFINITY) } else { (q, q) };
+    }
+    let error = quotient_error(a, b, q);
+    (round_down(q, error), round_up(q, error))
+}
+
+fn min_max(bounds: [(f64, f64); 4]) -> Interval {
+    let lo = bounds.iter().map(|b| b.0).fold(f64::INFINITY, f64::min);
+    let hi = bounds.iter().map(|b| b.1).fold(f64::NEG_INFINITY, f64::max);
+    Interval::new(lo, hi)
+}
+
+impl From<f64> for Interval {
+    fn from(x: f64) -> Self {
+        Self::point(x)
+    }
+}
+
+impl From<f32> for Interval {
+    fn from(x: f32) -> Self {
+        Self::point(x as f64)
+    }
+}
+
+/// The midpoint, rounded to f32.
+impl From<Interval> for f32 {
+    fn from(x: Interval) -> f32 {
+        x.mid() as f32
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(add_down(self.lo, rhs.lo), add_up(self.hi, rhs.hi))
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        min_max([
+            mul_bounds(self.lo, rhs.lo),
+            mul_bounds(self.lo, rhs.hi),
+            mul_bounds(self.hi, rhs.lo),
+            mul_bounds(self.hi, rhs.hi),
+        ])
+    }
+}
+
+impl Div for Interval {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        if rhs.contains(0.0) {
+            return Self::entire();
+        }
+        min_max([
+            div_bounds(self.lo, rhs.lo),
+            div_bounds(self.lo, rhs.hi),
+            div_bounds(self.hi, rhs.lo),
+            div_bounds(self.hi, rhs.hi),
+        ])
+    }
+}
+
+impl Neg for Interval {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.hi, -self.lo)
+    }
+}
+
+impl AddAssign for Interval {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+impl SubAssign for Interval {
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+impl MulAssign for Interval {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+impl Semiring for Interval {
+    fn zero() -> Self { Self::point(0.0) }
+    fn one() -> Self { Self::point(1.0) }
+}
+
+impl Ring for Interval {}
+
+impl Field for Interval {
+    fn fma(self, b: Self, c: Self) -> Self { self * b + c }
+
+    /// [mignitude, magnitude]: the smallest and largest |x| over the interval.
+    fn abs(self) -> Self {
+        if self.lo >= 0.0 {
+            self
+        } else if self.hi <= 0.0 {
+            -self
+        } else {
+            Self::new(0.0, self.hi.max(-self.lo))
+        }
+    }
+
+    /// The square root of the nonnegative part.
+    fn sqrt(self) -> Self {
+        assert!(self.hi >= 0.0, "Square root of a negative interval {}", self);
+        let sqrt_down = |x: f64| {
+            let s = x.sqrt();
+            // s^2 - x > 0 means s rounded up.
+            if s > 0.0 && s.is_finite() && s.mul_add(s, -x) > 0.0 { s.next_down() } else { s }
+        };
+        let sqrt_up = |x: f64| {
+            let s = x.sqrt();
+            if s.is_finite() && s.mul_add(s, -x) < 0.0 { s.next_up() } else { s }
+        };
+        Self::new(sqrt_down(self.lo.max(0.0)), sqrt_up(self.hi))
+    }
+}
+
+impl Matrix<f32> {
+    /// The same matrix with every entry as a point interval.
+    pub fn to_interval(&self) -> Matrix<Interval> {
+        Matrix::from(
+            self.data.iter()
+                .map(|row| row.iter().map(|&v| Interval::from(v)).collect::<Vec<Interval>>())
+                .collect::<Vec<Vec<Interval>>>()
+        )
+    }
+}
+
+/// Proves that A x = b has exactly one solution and encloses it in a box
+/// around the approximate solution `x`, by the Krawczyk-Rump test: with
+/// R ≈ A^-1, z = R (b - A x) and C = I - R A evaluated in interval
+/// arithmetic, any box Y with z + C Y strictly inside Y contains the error
+/// of `x`, and A is then nonsingular. Y is found by epsilon inflation
+/// starting from z.
+///
+/// Fails when the test does not close, which happens when A is singular or
+/// too ill-conditioned for R to be a good enough inverse, and when `x` is
+/// far from the solution.
+pub fn verify_solution(a: &Matrix<f32>, b: &Vector<f32>, x: &Vector<f32>) -> Result<Vector<Interval>, String> {
+    let n = a.rows;
+    if !a.is_square() || b.size() != n || x.size() != n {
+        return Err(format!("Expected a square matrix with matching vectors, got {}x{}, {} and {}", a.rows, a.cols, b.size(), x.size()));
+    }
+    // R only has to be close to A^-1, not enclose it: the midpoints of the
+    // interval inverse are an f64-accurate one.
+    let ai = a.to_interval();
+    let r = ai.clone().inverse()?;
+    if r.data.iter().flatten().any(|v| !v.mid().is_finite()) {
+        return Err("Could not verify the solution: no finite approximate inverse".to_string());
+    }
+    let r = Matrix::from(r.data.iter().map(|row| row.iter().map(|v| Interval::point(v.mid())).collect::<Vec<Interval>>()).collect::<Vec<_>>());
+    let xi = Vector::from(x.data.iter().map(|&v| Interval::from(v)).collect::<Vec<Interval>>());
+
+    let mut residual = Vector::from(b.data.iter().map(|&v| Interval::from(v)).collect::<Vec<Interval>>());
+    residual.sub(ai.mul_vec(xi.clone()));
+    let z = r.mul_vec(residual);
+    let mut c = Matrix::identity(n);
+    c.sub(r.mul_mat(ai));
+
+    let mut y = z.clone();
+    for _ in 0..MAX_INFLATIONS {
+        // Widen by 10% of the magnitude plus the smallest normal number, so
+        // that a start at exactly zero can grow too.
+        y = Vector::from(y.data.iter().map(|&v| {
+            let w = v.lo().abs().max(v.hi().abs()) * 0.1 + f64::MIN_POSITIVE;
+            Interval::new(v.lo() - w, v.hi() + w)
+        }).collect::<Vec<Interval>>());
+
+        let mut next = c.mul_vec(y.clone());
+        next.add(z.clone());
+        if y.data.iter().zip(&next.data).all(|(outer, inner)| outer.interior_contains(inner)) {
+            let mut enclosure = xi;
+            enclosure.add(next);
+            return Ok(enclosure);
+        }
+        y = next;
+    }
+    Err("Could not verify the solution: the matrix may be singular or too ill-conditioned".to_string())
+}
@@ -1,17 +1,19 @@
-use super::field::Field;
+use super::field::{Semiring, Ring, Field};
 use std::fmt;
 use crate::Vector;
+use super::row_ops::{RowOp, RowOpLog};
 use std::cmp::PartialOrd;
+use std::ops::Div;
 
 #[derive(Debug, Clone, PartialEq)]
 
-pub struct Matrix<K: Field>{
+pub struct Matrix<K: Semiring>{
     pub data: Vec<Vec<K>>,
     pub rows: usize,
     pub cols: usize
 }
 
-impl<K: Field> Matrix<K>{
+impl<K: Semiring> Matrix<K>{
 
     pub fn from<I, V>(data: I) -> Self 
     where
@@ -38,7 +40,7 @@ impl<K: Field> Matrix<K>{
     }
 
     pub fn get(&self, row: usize, col: usize) -> K{
-        self.data[row][col]
+        self.data[row][col].clone()
     }
 
     pub fn set(&mut self, row: usize, col: usize, val: K){
@@ -57,52 +59,37 @@ impl<K: Field> Matrix<K>{
         
         for i in 0..self.rows {
             for j in 0..self.cols{
-                self.set(i,j, self.data[i][j] + v.data[i][j]);
-            }
-        }
-    }
-    pub fn sub(&mut self, v: Matrix<K>){
-        for i in 0..self.rows {
-            for j in 0..self.cols{
-                self.set(i,j, self.data[i][j] - v.data[i][j]);
+                self.set(i,j, self.data[i][j].clone() + v.data[i][j].clone());
             }
         }
     }
     pub fn scl(&mut self, a: K){
         for i in 0..self.rows {
             for j in 0..self.cols{
-                self.set(i,j, self.data[i][j] * a);
+                self.set(i,j, self.data[i][j].clone() * a.clone());
             }
         }
     }
+    #[allow(clippy::needless_range_loop)]
     pub fn mul_vec(&self, vec: Vector<K>) -> Vector<K> {
         
         let mut result_data = vec![K::zero(); self.rows];       
         
         for i in 0..self.rows {
-            let mut sum = K::zero();
-            for j in 0..self.cols { 
-                sum += self.data[i][j].clone() * vec.data[j].clone(); 
-            }
-            result_data[i] = sum;
+            result_data[i] = K::sum_of_products((0..self.cols).map(|j| (self.data[i][j].clone(), vec.data[j].clone())));
         }
         
         Vector::from(result_data)
     }
 
+    #[allow(clippy::needless_range_loop)]
     pub fn mul_mat(&self, other: Matrix<K>) -> Matrix<K> {
         assert_eq!(self.cols, other.rows, "Error: The number of columns in A must equal the number of rows in B!");
         let mut result_data = vec![vec![K::zero(); other.cols]; self.rows];
 
         for i in 0..self.rows {         
             for j in 0..other.cols {   
-                let mut sum = K::zero();
-                
-                for k in 0..self.cols { 
-                    sum += self.data[i][k].clone() * other.data[k][j].clone();
-                }
-
-                result_data[i][j] = sum;
+                result_data[i][j] = K::sum_of_products((0..self.cols).map(|k| (self.data[i][k].clone(), other.data[k][j].clone())));
             }
         }
 
@@ -113,12 +100,13 @@ impl<K: Field> Matrix<K>{
 
         let mut sum = K::zero();
         for i in 0..self.rows {
-            sum += self.data[i][i];
+            sum += self.data[i][i].clone();
         }
         
         sum
     }
 
+    #[allow(clippy::needless_range_loop)]
     pub fn transpose(&self) -> Matrix<K>{
         let mut result_data = vec![vec![K::zero(); self.rows]; self.cols];
 
@@ -130,11 +118,236 @@ impl<K: Field> Matrix<K>{
         Matrix::from(result_data)
     }
 
-    
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        self.data.swap(a, b);
+    }
+
+    pub fn scale_row(&mut self, row: usize, factor: K) {
+        for j in 0..self.cols {
+            self.data[row][j] *= factor.clone();
+        }
+    }
+
+    /// R_target <- R_target + factor * R_source
+    pub fn add_row_multiple(&mut self, target: usize, source: usize, factor: K) {
+        for j in 0..self.cols {
+            let source_value = self.data[source][j].clone();
+            self.data[target][j] += factor.clone() * source_value;
+        }
+    }
+
+    pub fn swap_cols(&mut self, a: usize, b: usize) {
+        for i in 0..self.rows {
+            self.data[i].swap(a, b);
+        }
+    }
+
+    pub fn scale_col(&mut self, col: usize, factor: K) {
+        for i in 0..self.rows {
+            self.data[i][col] *= factor.clone();
+        }
+    }
+
+    /// C_target <- C_target + factor * C_source
+    pub fn add_col_multiple(&mut self, target: usize, source: usize, factor: K) {
+        for i in 0..self.rows {
+            let source_value = self.data[i][source].clone();
+            self.data[i][target] += factor.clone() * source_value;
+        }
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn identity(n: usize) -> Matrix<K> {
+        let mut result_data = vec![vec![K::zero(); n]; n];
+        for i in 0..n {
+            result_data[i][i] = K::one();
+        }
+        Matrix::from(result_data)
+    }
+
+    pub fn kronecker(&self, other: &Matrix<K>) -> Matrix<K> {
+        let mut result_data = vec![vec![K::zero(); self.cols * other.cols]; self.rows * other.rows];
+
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                for k in 0..other.rows {
+                    for l in 0..other.cols {
+                        result_data[i * other.rows + k][j * other.cols + l] = self.data[i][j].clone() * other.data[k][l].clone();
+                    }
+                }
+            }
+        }
+        Matrix { data: result_data, rows: self.rows * other.rows, cols: self.cols * other.cols }
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn hstack(matrices: &[&Matrix<K>]) -> Matrix<K> {
+        let rows = matrices.first().map_or(0, |m| m.rows);
+        let mut result_data = vec![Vec::new(); rows];
+
+        for m in matrices {
+            assert_eq!(m.rows, rows, "Error: hstack needs the same number of rows, got {} and {}", rows, m.rows);
+            for i in 0..rows {
+                result_data[i].extend_from_slice(&m.data[i]);
+            }
+        }
+        let cols = matrices.iter().map(|m| m.cols).sum();
+        Matrix { data: result_data, rows, cols }
+    }
+
+    pub fn vstack(matrices: &[&Matrix<K>]) -> Matrix<K> {
+        let cols = matrices.first().map_or(0, |m| m.cols);
+        let mut result_data = Vec::new();
+
+        for m in matrices {
+            assert_eq!(m.cols, cols, "Error: vstack needs the same number of columns, got {} and {}", cols, m.cols);
+            result_data.extend(m.data.iter().cloned());
+        }
+        let rows = result_data.len();
+        Matrix { data: result_data, rows, cols }
+    }
+
+    /// Assembles `[[A, B], [C, D]]`-style block rows into one matrix.
+    pub fn block(blocks: &[&[&Matrix<K>]]) -> Matrix<K> {
+        let block_rows: Vec<Matrix<K>> = blocks.iter().map(|row| Matrix::hstack(row)).collect();
+        Matrix::vstack(&block_rows.iter().collect::<Vec<_>>())
+    }
+
+    pub fn submatrix(&self, row: usize, col: usize, rows: usize, cols: usize) -> Matrix<K> {
+        assert!(row + rows <= self.rows && col + cols <= self.cols,
+            "Error: submatrix {}x{} at ({}, {}) exceeds {:?}", rows, cols, row, col, self.shape());
+        let result_data = (row..row + rows).map(|i| self.data[i][col..col + cols].to_vec()).collect();
+        Matrix { data: result_data, rows, cols }
+    }
+
+    /// A^k by repeated squaring; A^0 is the identity.
+    pub fn pow(&self, mut k: u32) -> Matrix<K> {
+        assert!(self.is_square(), "Error: Matrix must be square to take powers!");
+        let mut base = self.clone();
+        let mut result = Matrix::identity(self.rows);
+        while k > 0 {
+            if k & 1 == 1 {
+                result = result.mul_mat(base.clone());
+            }
+            k >>= 1;
+            if k > 0 {
+                base = base.mul_mat(base.clone());
+            }
+        }
+        result
+    }
 }
 
+impl<K: Ring> Matrix<K>{
+    pub fn sub(&mut self, v: Matrix<K>){
+        for i in 0..self.rows {
+            for j in 0..self.cols{
+                self.set(i,j, self.data[i][j].clone() - v.data[i][j].clone());
+            }
+        }
+    }
+}
+
+impl<K: Field> Matrix<K>{
+    /// R_row <- R_row / divisor. Zero entries are left as they are, so a
+    /// negative divisor does not turn them into -0.
+    pub fn divide_row(&mut self, row: usize, divisor: K) {
+        for j in 0..self.cols {
+            if self.data[row][j] != K::zero() {
+                self.data[row][j] = self.data[row][j].clone() / divisor.clone();
+            }
+        }
+    }
+
+    fn row_op(&mut self, op: RowOp<K>, log: &mut Option<&mut RowOpLog<K>>) {
+        op.apply(self);
+        if let Some(log) = log {
+            log.record(op, self);
+        }
+    }
+
+    // Divides the row by its pivot, rather than scaling by 1 / pivot, so the
+    // pivot becomes exactly one.
+    fn normalize_row(&mut self, row: usize, pivot_col: usize, log: &mut Option<&mut RowOpLog<K>>) {
+        let pivot = self.data[row][pivot_col].clone();
+        self.row_op(RowOp::Divide(row, pivot), log);
+    }
+
+    /// sqrt of the sum of squared entries, computed in K.
+    pub fn frobenius_norm(&self) -> K {
+        let mut sum = K::zero();
+        for row in &self.data {
+            for x in row {
+                sum += x.clone() * x.clone();
+            }
+        }
+        sum.sqrt()
+    }
+}
+
+impl<K: Ring + Div<Output = K>> Matrix<K>{
+    // Fraction-free (Bareiss) determinant. Each division is exact, so integer
+    // entries stay integers and every stored entry is a minor of the matrix.
+    // The dividend is formed first, though: a difference of products of two
+    // minors, which is about twice the size of either.
+    pub(crate) fn bareiss(&self) -> K {
+        assert!(self.is_square(), "Error: Matrix must be square to compute determinant!");
+        let n = self.rows;
+        let mut m = self.data.clone();
+        let mut sign = K::one();
+        let mut prev = K::one();
+
+        for k in 0..n.saturating_sub(1) {
+            if m[k][k] == K::zero() {
+                match (k + 1..n).find(|&i| m[i][k] != K::zero()) {
+                    Some(i) => {
+                        m.swap(i, k);
+                        sign = -sign;
+                    }
+                    None => return K::zero(),
+                }
+            }
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    m[i][j] = (m[i][j].clone() * m[k][k].clone() - m[i][k].clone() * m[k][j].clone()) / prev.clone();
+                }
+                m[i][k] = K::zero();
+            }
+            prev = m[k][k].clone();
+        }
+
+        if n == 0 { K::one() } else { sign * m[n - 1][n - 1].clone() }
+    }
+}
+
+macro_rules! impl_integer_determinant {
+    ($($t:ty),*) => {
+        $(
+            impl Matrix<$t> {
+                /// Exact, by Bareiss elimination. Every stored entry is a minor
+                /// of the matrix, but each step multiplies two minors before
+                /// an exact division, so the intermediate product of two
+                /// minors must fit in the type, not just the determinant.
+                pub fn determinant(&self) -> $t {
+                    self.bareiss()
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_determinant!(i8, i16, i32, i64, i128, isize);
+
 impl <K: Field + PartialOrd> Matrix<K>{
     pub fn row_echelon(&mut self) -> Matrix<K> {
+        self.row_echelon_impl(&mut None)
+    }
+
+    pub fn row_echelon_with_log(&mut self, log: &mut RowOpLog<K>) -> Matrix<K> {
+        self.row_echelon_impl(&mut Some(log))
+    }
+
+    fn row_echelon_impl(&mut self, log: &mut Option<&mut RowOpLog<K>>) -> Matrix<K> {
         let mut current_row = 0;
 
         for i in 0..self.cols {
@@ -142,36 +355,23 @@ impl <K: Field + PartialOrd> Matrix<K>{
                 break;
             }
 
-            let mut max_row = current_row;
-            for row in current_row + 1..self.rows {
-                if self.data[row][i].abs() > self.data[max_row][i].abs() {
-                    max_row = row;
-                }
-            }
+            let max_row = self.pivot_row(current_row, i);
 
             if self.data[max_row][i] == K::zero() {
                 continue;
             }
 
             if current_row != max_row {
-                for j in 0..self.data[current_row].len() {
-                    let temp = self.data[current_row][j];
-                    self.data[current_row][j] = self.data[max_row][j];
-                    self.data[max_row][j] = temp;
-                }
+                self.row_op(RowOp::Swap(current_row, max_row), log);
             }
 
-            let pivot = self.data[current_row][i];
-            for j in i..self.data[current_row].len() {
-                self.data[current_row][j] = self.data[current_row][j] / pivot;
-            }
+            self.normalize_row(current_row, i, log);
 
             for row in current_row + 1..self.rows {
-                let pivot = self.data[current_row][i];
-                let factor = self.data[row][i] / pivot;
-                for j in i..self.data[row].len() {
-                        let current_row_value = self.data[current_row][j];
-                        self.data[row][j] -= factor * current_row_value;
+                let pivot = self.data[current_row][i].clone();
+                let factor = self.data[row][i].clone() / pivot;
+                if factor != K::zero() {
+                    self.row_op(RowOp::AddMultiple { target: row, source: current_row, factor: -factor }, log);
                 }
             }
 
@@ -181,55 +381,75 @@ impl <K: Field + PartialOrd> Matrix<K>{
         Matrix::from(self.data.clone())
     }
 
+    /// Reduced row echelon form: row echelon form with every pivot column
+    /// also cleared above its pivot.
+    pub fn rref(&mut self) -> Matrix<K> {
+        self.rref_impl(&mut None)
+    }
+
+    pub fn rref_with_log(&mut self, log: &mut RowOpLog<K>) -> Matrix<K> {
+        self.rref_impl(&mut Some(log))
+    }
+
+    fn rref_impl(&mut self, log: &mut Option<&mut RowOpLog<K>>) -> Matrix<K> {
+        self.row_echelon_impl(log);
+
+        for row in (0..self.rows).rev() {
+            let pivot_col = match (0..self.cols).find(|&j| self.data[row][j] != K::zero()) {
+                Some(col) => col,
+                None => continue,
+            };
+            for above in 0..row {
+                let factor = self.data[above][pivot_col].clone();
+                if factor != K::zero() {
+                    self.row_op(RowOp::AddMultiple { target: above, source: row, factor: -factor }, log);
+                }
+            }
+        }
+
+        Matrix::from(self.data.clone())
+    }
+
     pub fn determinant(&mut self) -> K {
+        self.determinant_impl(&mut None)
+    }
+
+    pub fn determinant_with_log(&mut self, log: &mut RowOpLog<K>) -> K {
+        self.determinant_impl(&mut Some(log))
+    }
+
+    fn determinant_impl(&mut self, log: &mut Option<&mut RowOpLog<K>>) -> K {
         if self.rows != self.cols {
             panic!("Determinant only defined for square matrices");
         }
 
         let mut swap_count = 0;
-        let mut current_row = 0;
         let n = self.rows;
 
-        for i in 0..self.cols {
-            if current_row >= self.rows {
-                break;
-            }
-
-            let mut max_row = current_row;
-            for row in current_row + 1..self.rows {
-                if self.data[row][i].abs() > self.data[max_row][i].abs() {
-                    max_row = row;
-                }
-            }
+        for i in 0..n {
+            let max_row = self.pivot_row(i, i);
 
             if self.data[max_row][i] == K::zero() {
                 return K::zero();
             }
 
-            if current_row != max_row {
-                for j in 0..self.data[current_row].len() {
-                    let temp = self.data[current_row][j];
-                    self.data[current_row][j] = self.data[max_row][j];
-                    self.data[max_row][j] = temp;
-                }
+            if i != max_row {
+                self.row_op(RowOp::Swap(i, max_row), log);
                 swap_count += 1;
             }
 
-            for row in current_row + 1..self.rows {
-                let pivot = self.data[current_row][i];
-                let factor = self.data[row][i] / pivot;
-                for j in i..self.data[row].len() {
-                        let current_row_value = self.data[current_row][j];
-                        self.data[row][j] -= factor * current_row_value;
+            for row in i + 1..n {
+                let pivot = self.data[i][i].clone();
+                let factor = self.data[row][i].clone() / pivot;
+                if factor != K::zero() {
+                    self.row_op(RowOp::AddMultiple { target: row, source: i, factor: -factor }, log);
                 }
             }
-
-            current_row += 1;
         }
 
         let mut det = K::one();
         for i in 0..n {
-            det = det * self.data[i][i];
+            det *= self.data[i][i].clone();
         }
 
         if swap_count % 2 == 1 {
@@ -240,86 +460,74 @@ impl <K: Field + PartialOrd> Matrix<K>{
     }
 
     pub fn inverse(&mut self) -> Result<Matrix<K>, String> {
+        self.inverse_impl(&mut None)
+    }
+
+    /// Records the operations applied to the augmented matrix `[A | I]`; their
+    /// elementary matrices multiply out to the inverse.
+    pub fn inverse_with_log(&mut self, log: &mut RowOpLog<K>) -> Result<Matrix<K>, String> {
+        self.inverse_impl(&mut Some(log))
+    }
+
+    fn inverse_impl(&mut self, log: &mut Option<&mut RowOpLog<K>>) -> Result<Matrix<K>, String> {
         if self.rows != self.cols {
             return Err("Matrix must be square".to_string());
         }
 
         let n = self.rows;
 
-        let mut augmented = Vec::new();
-        for i in 0..n {
-            let mut row = self.data[i].clone();
-            
-            for j in 0..n {
-                if i == j {
-                    row.push(K::one());
-                } else {
-                    row.push(K::zero());
-                }
-            }
-            augmented.push(row);
-        }
-        self.data = augmented;
+        *self = Matrix::hstack(&[self, &Matrix::identity(n)]);
 
         for i in 0..n {
-            let mut max_row = i;
-            for row in i + 1..n {
-                if self.data[row][i].abs() > self.data[max_row][i].abs() {
-                    max_row = row;
-                }
-            }
+            let max_row = self.pivot_row(i, i);
 
             if self.data[max_row][i] == K::zero() {
                 return Err("Matrix is singular".to_string());
             }
 
             if i != max_row {
-                for j in 0..2 * n {
-                    let temp = self.data[i][j];
-                    self.data[i][j] = self.data[max_row][j];
-                    self.data[max_row][j] = temp;
-                }
+                self.row_op(RowOp::Swap(i, max_row), log);
             }
 
             for row in i + 1..n {
-                let pivot = self.data[i][i];
-                let factor = self.data[row][i] / pivot;
-                
-                for j in i..2 * n {
-                    let pivot_value = self.data[i][j];
-                    self.data[row][j] -= factor * pivot_value;
+                let pivot = self.data[i][i].clone();
+                let factor = self.data[row][i].clone() / pivot;
+                if factor != K::zero() {
+                    self.row_op(RowOp::AddMultiple { target: row, source: i, factor: -factor }, log);
                 }
             }
         }
 
         for i in (0..n).rev() {
-            let pivot = self.data[i][i];
-            for j in 0..2 * n {
-                self.data[i][j] = self.data[i][j] / pivot;
-            }
+            self.normalize_row(i, i, log);
 
             for row in 0..i {
-                let pivot_val = self.data[row][i];
-                for j in 0..2 * n {
-                    let pivot_value = self.data[i][j];
-                    self.data[row][j] -= pivot_val * pivot_value;
+                let factor = self.data[row][i].clone();
+                if factor != K::zero() {
+                    self.row_op(RowOp::AddMultiple { target: row, source: i, factor: -factor }, log);
                 }
             }
         }
 
-        let mut inverse_data = Vec::new();
-        for i in 0..n {
-            let mut inv_row = Vec::new();
-            for j in n..2 * n {
-                inv_row.push(self.data[i][j]);
+        Ok(self.submatrix(0, n, n, n))
+    }
+
+    fn pivot_row(&self, from: usize, col: usize) -> usize {
+        let mut max_row = from;
+        for row in from + 1..self.rows {
+            if self.data[row][col].clone().abs() > self.data[max_row][col].clone().abs() {
+                max_row = row;
             }
-            inverse_data.push(inv_row);
         }
-
-        Ok(Matrix::from(inverse_data))
+        max_row
     }
+
     pub fn rank(&mut self) -> usize{
         self.row_echelon();
+        self.nonzero_rows()
+    }
+
+    pub(crate) fn nonzero_rows(&self) -> usize{
         let mut rank: usize = 0;
     
         for i in 0..self.rows {
@@ -351,7 +559,7 @@ pub fn projection(fov: f32, ratio: f32, near: f32, far: f32) -> Matrix<f32>{
     ])
 }
 
-impl<K: Field> fmt::Display for Matrix<K> {
+impl<K: Semiring> fmt::Display for Matrix<K> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for row in &self.data {
             let row_str: Vec<String> = row.iter().map(|v| format!("{}", v)).collect();
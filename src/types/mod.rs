@@ -1,15 +1,66 @@
 pub mod vector;
 pub mod matrix;
 pub mod field;
+pub mod real;
 pub mod lerp;
 pub mod complex;
+pub mod quaternion;
+pub mod dual;
+pub mod interval;
+pub mod fixed;
+pub mod half;
+pub mod expr;
+pub mod polynomial;
+pub mod roots;
+pub mod block;
+pub mod row_ops;
+pub mod explain;
+pub mod rational;
+pub mod bigint;
+pub mod big_rational;
+pub mod tropical;
+pub mod boolean;
+pub mod prime_field;
+pub mod gf256;
 
 pub use vector::Vector;
 pub use matrix::Matrix;
+pub use field::Semiring;
+pub use field::Ring;
 pub use field::Field;
+pub use real::Real;
 pub use vector::linear_combination;
 pub use vector::angle_cos;
 pub use vector::cross_product;
 pub use matrix::projection;
 pub use lerp::lerp;
-pub use complex::Complex;
\ No newline at end of file
+pub use complex::Complex;
+pub use quaternion::Quaternion;
+pub use dual::Dual;
+pub use dual::gradient;
+pub use dual::jacobian;
+pub use interval::Interval;
+pub use interval::verify_solution;
+pub use fixed::Fixed;
+pub use fixed::FixedError;
+pub use fixed::projection_fixed;
+pub use half::F16;
+pub use half::BF16;
+pub use expr::Expr;
+pub use polynomial::Polynomial;
+pub use roots::roots;
+pub use block::BlockMatrix;
+pub use block::BlockLu;
+pub use row_ops::RowOp;
+pub use row_ops::RowOpLog;
+pub use explain::EliminationTrace;
+pub use explain::TraceFormat;
+pub use rational::Rational;
+pub use bigint::BigInt;
+pub use big_rational::BigRational;
+pub use tropical::MinPlus;
+pub use tropical::MaxPlus;
+pub use boolean::Boolean;
+pub use prime_field::Fp;
+pub use prime_field::Gf2;
+pub use gf256::Gf256;
\ No newline at end of file
@@ -0,0 +1,328 @@
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Neg};
+use super::field::Field;
+use crate::Matrix;
+
+/// Polynomial with coefficients in a field, stored lowest degree first with
+/// no trailing zero coefficients, so the zero polynomial has none.
+///
+/// Division, `gcd` and everything that decides whether a coefficient is zero
+/// are exact over exact fields (`Rational`, `BigRational`, `Fp`, `Gf256`).
+/// Over floats a remainder that should vanish is usually only small, so
+/// `gcd` and `minimal_polynomial` are unreliable there.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial<K: Field> {
+    coeffs: Vec<K>,
+}
+
+impl<K: Field> Polynomial<K> {
+    /// From coefficients, lowest degree first.
+    pub fn new(coeffs: impl Into<Vec<K>>) -> Self {
+        let mut p = Self { coeffs: coeffs.into() };
+        p.trim();
+        p
+    }
+
+    pub fn zero() -> Self {
+        Self { coeffs: Vec::new() }
+    }
+
+    pub fn constant(c: K) -> Self {
+        Self::new(vec![c])
+    }
+
+    /// The polynomial x.
+    pub fn x() -> Self {
+        Self::new(vec![K::zero(), K::one()])
+    }
+
+    /// The product of (x - r) over the roots.
+    pub fn from_roots(roots: &[K]) -> Self {
+        roots.iter().fold(Self::constant(K::one()), |p, r| p * Self::new(vec![-r.clone(), K::one()]))
+    }
+
+    fn trim(&mut self) {
+        while self.coeffs.last() == Some(&K::zero()) {
+            self.coeffs.pop();
+        }
+    }
+
+    /// Coefficients, lowest degree first.
+    pub fn coeffs(&self) -> &[K] {
+        &self.coeffs
+    }
+
+    /// `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// Coefficient of the highest power, zero for the zero polynomial.
+    pub fn leading(&self) -> K {
+        self.coeffs.last().cloned().unwrap_or(K::zero())
+    }
+
+    /// Divided by its leading coefficient; the zero polynomial stays zero.
+    pub fn monic(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        self.scale(K::one() / self.leading())
+    }
+
+    pub fn scale(&self, c: K) -> Self {
+        Self::new(self.coeffs.iter().map(|a| a.clone() * c.clone()).collect::<Vec<K>>())
+    }
+
+    /// Value at x, by Horner's rule.
+    pub fn evaluate(&self, x: K) -> K {
+        self.coeffs.iter().rev().fold(K::zero(), |acc, c| acc * x.clone() + c.clone())
+    }
+
+    pub fn derivative(&self) -> Self {
+        let mut i = K::zero();
+        let coeffs = self.coeffs.iter().skip(1).map(|c| {
+            i += K::one();
+            c.clone() * i.clone()
+        });
+        Self::new(coeffs.collect::<Vec<K>>())
+    }
+
+    /// Quotient and remainder of Euclidean division, with deg r < deg divisor.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let d = divisor.degree().expect("Polynomial division by zero");
+        let lead_inv = K::one() / divisor.leading();
+        let mut rem = self.coeffs.clone();
+        let mut quot = vec![K::zero(); rem.len().saturating_sub(d)];
+
+        for i in (0..quot.len()).rev() {
+            let q = rem[i + d].clone() * lead_inv.clone();
+            quot[i] = q.clone();
+            for (j, c) in divisor.coeffs.iter().enumerate() {
+                rem[i + j] -= q.clone() * c.clone();
+            }
+        }
+        rem.truncate(d);
+        (Self::new(quot), Self::new(rem))
+    }
+
+    /// Monic greatest common divisor, by Euclid's algorithm; zero only when
+    /// both are zero.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            (a, b) = (b, r);
+        }
+        a.monic()
+    }
+}
+
+impl<K: Field> Add for Polynomial<K> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let (mut long, short) = if self.coeffs.len() >= rhs.coeffs.len() { (self, rhs) } else { (rhs, self) };
+        for (a, b) in long.coeffs.iter_mut().zip(&short.coeffs) {
+            *a += b.clone();
+        }
+        long.trim();
+        long
+    }
+}
+
+impl<K: Field> Sub for Polynomial<K> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<K: Field> Mul for Polynomial<K> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero();
+        }
+        let mut coeffs = vec![K::zero(); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] += a.clone() * b.clone();
+            }
+        }
+        Self::new(coeffs)
+    }
+}
+
+impl<K: Field> Neg for Polynomial<K> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self { coeffs: self.coeffs.into_iter().map(|c| -c).collect() }
+    }
+}
+
+/// Highest degree first, e.g. `x^3 - 2x + 1`. A coefficient whose own
+/// display has an inner sign, such as a complex number, is parenthesized.
+impl<K: Field> fmt::Display for Polynomial<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let mut first = true;
+        for (k, c) in self.coeffs.iter().enumerate().rev() {
+            if *c == K::zero() {
+                continue;
+            }
+            let text = c.to_string();
+            let (negative, magnitude) = match text.strip_prefix('-') {
+                Some(rest) if !rest.contains(['+', '-']) => (true, rest.to_string()),
+                _ if text.chars().skip(1).any(|ch| ch == '+' || ch == '-') => (false, format!("({})", text)),
+                _ => (false, text),
+            };
+            match (first, negative) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => {}
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+            }
+            first = false;
+
+            let unit = magnitude == "1" && k > 0;
+            // A symbolic coefficient such as 2*a would run into the x.
+            if k > 0 && magnitude.contains(|ch: char| ch.is_alphabetic() || ch == '*') && !magnitude.starts_with('(') {
+                write!(f, "({})", magnitude)?;
+            } else if !unit {
+                write!(f, "{}", magnitude)?;
+            }
+            match k {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", k)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<K: Field> Matrix<K> {
+    /// det(xI - A), monic of degree n. The matrix is brought to upper
+    /// Hessenberg form H by Gaussian similarity transforms, then the
+    /// characteristic polynomials of the leading blocks of H follow from
+    ///   p_m = (x - h_mm) p_(m-1) - sum_(i<m) h_im h_(i+1,i) ... h_(m,m-1) p_(i-1).
+    /// O(n^3) operations and no division by integers, so it is exact over any
+    /// exact field, finite ones included.
+    pub fn characteristic_polynomial(&self) -> Polynomial<K> {
+        assert!(self.is_square(), "Error: Matrix must be square to compute its characteristic polynomial!");
+        let n = self.rows;
+        let h = self.hessenberg();
+        let x = Polynomial::x();
+
+        let mut p = vec![Polynomial::constant(K::one())];
+        for m in 0..n {
+            let mut next = (x.clone() - Polynomial::constant(h[m][m].clone())) * p[m].clone();
+            let mut subdiagonal = K::one();
+            for i in (0..m).rev() {
+                subdiagonal *= h[i + 1][i].clone();
+                next = next - p[i].scale(h[i][m].clone() * subdiagonal.clone());
+            }
+            p.push(next);
+        }
+        p.pop().unwrap_or(Polynomial::constant(K::one()))
+    }
+
+    // Upper Hessenberg matrix similar to self. Column j is cleared below the
+    // subdiagonal with the largest available pivot; each row operation is
+    // paired with the inverse column operation to keep the similarity.
+    #[allow(clippy::needless_range_loop)]
+    fn hessenberg(&self) -> Vec<Vec<K>> {
+        let n = self.rows;
+        let mut h = self.data.clone();
+        let magnitude = |x: K| -> f32 { x.abs().into() };
+
+        for j in 0..n.saturating_sub(2) {
+            let pivot = (j + 1..n)
+                .filter(|&i| h[i][j] != K::zero())
+                .fold(None, |best: Option<usize>, i| match best {
+                    Some(b) if magnitude(h[b][j].clone()) >= magnitude(h[i][j].clone()) => Some(b),
+                    _ => Some(i),
+                });
+            let pivot = match pivot {
+                Some(p) => p,
+                None => continue,
+            };
+            if pivot != j + 1 {
+                h.swap(pivot, j + 1);
+                for row in h.iter_mut() {
+                    row.swap(pivot, j + 1);
+                }
+            }
+            for i in j + 2..n {
+                let u = h[i][j].clone() / h[j + 1][j].clone();
+                if u == K::zero() {
+                    continue;
+                }
+                for c in 0..n {
+                    let v = h[j + 1][c].clone();
+                    h[i][c] -= u.clone() * v;
+                }
+                for row in h.iter_mut() {
+                    let v = row[i].clone();
+                    row[j + 1] += u.clone() * v;
+                }
+            }
+        }
+        h
+    }
+
+    /// p(A) by Horner's rule, for checking Cayley-Hamilton: the result for
+    /// the characteristic or minimal polynomial is the zero matrix.
+    pub fn evaluate_polynomial(&self, p: &Polynomial<K>) -> Matrix<K> {
+        assert!(self.is_square(), "Error: Matrix must be square to evaluate a polynomial at it!");
+        let n = self.rows;
+        let mut result = Matrix::from(vec![vec![K::zero(); n]; n]);
+        for c in p.coeffs().iter().rev() {
+            result = result.mul_mat(self.clone());
+            for i in 0..n {
+                result.data[i][i] += c.clone();
+            }
+        }
+        result
+    }
+}
+
+impl<K: Field + PartialOrd> Matrix<K> {
+    /// The monic polynomial of least degree with p(A) = 0: found as the first
+    /// power A^k that is a combination of I, A, ..., A^(k-1), whose
+    /// coefficients are read off the rref of the flattened powers. Over
+    /// floats this falls back to the characteristic polynomial when no
+    /// power comes out exactly dependent.
+    pub fn minimal_polynomial(&self) -> Polynomial<K> {
+        assert!(self.is_square(), "Error: Matrix must be square to compute its minimal polynomial!");
+        let n = self.rows;
+        let mut powers: Vec<Vec<K>> = Vec::new();
+        let mut power = Matrix::identity(n);
+
+        for k in 0..=n {
+            powers.push(power.data.iter().flatten().cloned().collect());
+            // Columns are the flattened powers I, A, ..., A^k.
+            let mut columns = Matrix::from((0..n * n).map(|r| powers.iter().map(|p| p[r].clone()).collect::<Vec<K>>()).collect::<Vec<_>>());
+            let rref = columns.rref();
+            let last_is_pivot = rref.data.iter().any(|row| {
+                row.iter().position(|x| *x != K::zero()) == Some(k)
+            });
+            if !last_is_pivot {
+                // Pivots are exactly the columns 0..k, in rows 0..k.
+                let mut coeffs: Vec<K> = (0..k).map(|i| -rref.data[i][k].clone()).collect();
+                coeffs.push(K::one());
+                return Polynomial::new(coeffs);
+            }
+            power = power.mul_mat(self.clone());
+        }
+        // Only reachable over floats, where rounding can keep all n + 1
+        // powers independent; Cayley-Hamilton gives a valid answer.
+        self.characteristic_polynomial()
+    }
+}
@@ -0,0 +1,199 @@
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign};
+use super::field::{Semiring, Ring, Field};
+use crate::Matrix;
+
+/// Element of the prime field GF(P), stored as its representative in 0..P.
+/// `P` must be prime; nothing checks this, and with a composite modulus
+/// division by a zero divisor panics. Products go through u128, so any
+/// `P < 2^64` works.
+///
+/// The ordering compares representatives. It has no algebraic meaning and is
+/// only there so `row_echelon`, `rank`, `determinant` and `inverse` can pick
+/// pivots; over a field any nonzero pivot is exact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fp<const P: u64>(u64);
+
+/// GF(2): addition is xor, multiplication is and.
+pub type Gf2 = Fp<2>;
+
+impl<const P: u64> Fp<P> {
+    pub fn new(value: u64) -> Self {
+        Fp(value % P)
+    }
+
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let (mut base, mut result) = (self, Self::one());
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Multiplicative inverse by the extended Euclidean algorithm.
+    pub fn inverse(self) -> Option<Self> {
+        let (mut r0, mut r1) = (P as i128, self.0 as i128);
+        let (mut t0, mut t1) = (0i128, 1i128);
+        while r1 != 0 {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (t0, t1) = (t1, t0 - q * t1);
+        }
+        if r0 != 1 {
+            return None;
+        }
+        Some(Fp(t0.rem_euclid(P as i128) as u64))
+    }
+
+    /// Tonelli-Shanks square root, `None` for a quadratic non-residue.
+    pub fn checked_sqrt(self) -> Option<Self> {
+        if self.0 == 0 || P == 2 {
+            return Some(self);
+        }
+        if self.pow((P - 1) / 2) != Self::one() {
+            return None;
+        }
+        let (mut q, mut s) = (P - 1, 0);
+        while q % 2 == 0 {
+            q /= 2;
+            s += 1;
+        }
+        let z = (2..P).map(Fp).find(|z| z.pow((P - 1) / 2) != Self::one())?;
+
+        let (mut m, mut c, mut t, mut r) = (s, z.pow(q), self.pow(q), self.pow(q.div_ceil(2)));
+        while t != Self::one() {
+            let mut i = 0;
+            let mut t2 = t;
+            while t2 != Self::one() {
+                t2 *= t2;
+                i += 1;
+            }
+            let b = c.pow(1 << (m - i - 1));
+            m = i;
+            c = b * b;
+            t *= c;
+            r *= b;
+        }
+        Some(r)
+    }
+}
+
+impl<const P: u64> From<u64> for Fp<P> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const P: u64> From<i64> for Fp<P> {
+    fn from(value: i64) -> Self {
+        Fp((value as i128).rem_euclid(P as i128) as u64)
+    }
+}
+
+impl<const P: u64> From<Fp<P>> for f32 {
+    fn from(x: Fp<P>) -> f32 {
+        x.0 as f32
+    }
+}
+
+impl<const P: u64> Add for Fp<P> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Fp(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Sub for Fp<P> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<const P: u64> Mul for Fp<P> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Fp((self.0 as u128 * rhs.0 as u128 % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Div for Fp<P> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        self.mul(rhs.inverse().expect("GF(p) division by zero"))
+    }
+}
+
+impl<const P: u64> Neg for Fp<P> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        if self.0 == 0 { self } else { Fp(P - self.0) }
+    }
+}
+
+impl<const P: u64> AddAssign for Fp<P> {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+impl<const P: u64> SubAssign for Fp<P> {
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+impl<const P: u64> MulAssign for Fp<P> {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl<const P: u64> fmt::Display for Fp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u64> Semiring for Fp<P> {
+    fn zero() -> Self { Fp(0) }
+    fn one() -> Self { Fp(1 % P) }
+}
+
+impl<const P: u64> Ring for Fp<P> {}
+
+impl<const P: u64> Field for Fp<P> {
+    fn fma(self, b: Self, c: Self) -> Self { self * b + c }
+    fn abs(self) -> Self { self }
+    fn sqrt(self) -> Self {
+        self.checked_sqrt().expect("GF(p) square root of a non-residue")
+    }
+}
+
+impl<const P: u64> Matrix<Fp<P>> {
+    /// Same result as `mul_mat`, but each dot product is summed in u128 and
+    /// reduced only when another product could overflow the accumulator; for
+    /// P < 2^32 that is once per entry.
+    #[allow(clippy::needless_range_loop)]
+    pub fn mul_mat_lazy(&self, other: &Matrix<Fp<P>>) -> Matrix<Fp<P>> {
+        assert_eq!(self.cols, other.rows, "Error: The number of columns in A must equal the number of rows in B!");
+        let max_product = (P as u128 - 1) * (P as u128 - 1);
+        let headroom = u128::MAX - max_product;
+        let mut result_data = vec![vec![Fp(0); other.cols]; self.rows];
+
+        for i in 0..self.rows {
+            for j in 0..other.cols {
+                let mut sum = 0u128;
+                for k in 0..self.cols {
+                    if sum > headroom {
+                        sum %= P as u128;
+                    }
+                    sum += self.data[i][k].0 as u128 * other.data[k][j].0 as u128;
+                }
+                result_data[i][j] = Fp((sum % P as u128) as u64);
+            }
+        }
+
+        Matrix::from(result_data)
+    }
+}
@@ -0,0 +1,297 @@
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign};
+use super::field::{Semiring, Ring};
+use super::lerp::Lerp;
+use crate::{Matrix, Vector};
+
+// Largest entry error accepted by `from_rotation_matrix`.
+const ROTATION_TOLERANCE: f32 = 1e-3;
+
+/// Quaternion w + xi + yj + zk. Unit quaternions are rotations of 3-space:
+/// q rotates v to q v q^-1, and q1 * q2 applies q2 first.
+///
+/// Multiplication does not commute, so this is a `Ring` but not a `Field`:
+/// matrix products over it are fine, determinants and inverses are not.
+/// Division is on the right, q / r = q r^-1.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Quaternion {
+    pub w: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Quaternion {
+    pub fn from(w: f32, x: f32, y: f32, z: f32) -> Self {
+        Self { w, x, y, z }
+    }
+
+    /// The identity rotation.
+    pub fn identity() -> Self {
+        Self::from(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Rotation by `angle` radians about `axis`, counterclockwise looking
+    /// down the axis towards the origin. The axis need not be unit length.
+    pub fn from_axis_angle(axis: &Vector<f32>, angle: f32) -> Self {
+        assert_eq!(axis.size(), 3, "Rotation axis must have 3 components, got {}", axis.size());
+        let norm = axis.norm();
+        assert!(norm > 0.0, "Rotation axis must be nonzero");
+        let s = (angle / 2.0).sin() / norm;
+        Self::from((angle / 2.0).cos(), axis.data[0] * s, axis.data[1] * s, axis.data[2] * s)
+    }
+
+    /// Unit axis and angle in [0, π]; the x axis for the identity.
+    pub fn to_axis_angle(&self) -> (Vector<f32>, f32) {
+        let q = if self.w < 0.0 { -self.normalize() } else { self.normalize() };
+        let s = (q.x * q.x + q.y * q.y + q.z * q.z).sqrt();
+        if s == 0.0 {
+            return (Vector::from([1.0, 0.0, 0.0]), 0.0);
+        }
+        (Vector::from([q.x / s, q.y / s, q.z / s]), 2.0 * s.atan2(q.w))
+    }
+
+    /// Yaw about z, then pitch about the new y, then roll about the new x
+    /// (intrinsic Z-Y'-X''), as used for aircraft and most rigs.
+    pub fn from_euler(roll: f32, pitch: f32, yaw: f32) -> Self {
+        let (sr, cr) = (roll / 2.0).sin_cos();
+        let (sp, cp) = (pitch / 2.0).sin_cos();
+        let (sy, cy) = (yaw / 2.0).sin_cos();
+        Self::from(
+            cr * cp * cy + sr * sp * sy,
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy,
+        )
+    }
+
+    /// (roll, pitch, yaw) in the convention of `from_euler`. At pitch ±π/2
+    /// roll and yaw are not separable, and roll is reported as zero.
+    pub fn to_euler(&self) -> (f32, f32, f32) {
+        let q = self.normalize();
+        let sin_pitch = (2.0 * (q.w * q.y - q.x * q.z)).clamp(-1.0, 1.0);
+        let pitch = sin_pitch.asin();
+        if sin_pitch.abs() > 1.0 - 1e-6 {
+            let yaw = -2.0 * sin_pitch.signum() * q.x.atan2(q.w);
+            return (0.0, pitch, yaw);
+        }
+        let roll = (2.0 * (q.w * q.x + q.y * q.z)).atan2(1.0 - 2.0 * (q.x * q.x + q.y * q.y));
+        let yaw = (2.0 * (q.w * q.z + q.x * q.y)).atan2(1.0 - 2.0 * (q.y * q.y + q.z * q.z));
+        (roll, pitch, yaw)
+    }
+
+    /// Rotation from a 3x3 rotation matrix, or the upper-left block of a 4x4
+    /// one, for column vectors (v' = R v). Uses Shepperd's method: the
+    /// largest of the four diagonal combinations is divided by, so the
+    /// result stays accurate near 180° turns.
+    pub fn from_rotation_matrix(m: &Matrix<f32>) -> Result<Self, String> {
+        if !((m.rows == 3 && m.cols == 3) || (m.rows == 4 && m.cols == 4)) {
+            return Err(format!("Rotation matrix must be 3x3 or 4x4, got {}x{}", m.rows, m.cols));
+        }
+        let r = |i: usize, j: usize| m.data[i][j];
+        let trace = r(0, 0) + r(1, 1) + r(2, 2);
+        let q = if trace > r(0, 0).max(r(1, 1)).max(r(2, 2)) {
+            let s = 2.0 * (1.0 + trace).sqrt();
+            Self::from(s / 4.0, (r(2, 1) - r(1, 2)) / s, (r(0, 2) - r(2, 0)) / s, (r(1, 0) - r(0, 1)) / s)
+        } else if r(0, 0) >= r(1, 1) && r(0, 0) >= r(2, 2) {
+            let s = 2.0 * (1.0 + r(0, 0) - r(1, 1) - r(2, 2)).sqrt();
+            Self::from((r(2, 1) - r(1, 2)) / s, s / 4.0, (r(0, 1) + r(1, 0)) / s, (r(0, 2) + r(2, 0)) / s)
+        } else if r(1, 1) >= r(2, 2) {
+            let s = 2.0 * (1.0 - r(0, 0) + r(1, 1) - r(2, 2)).sqrt();
+            Self::from((r(0, 2) - r(2, 0)) / s, (r(0, 1) + r(1, 0)) / s, s / 4.0, (r(1, 2) + r(2, 1)) / s)
+        } else {
+            let s = 2.0 * (1.0 - r(0, 0) - r(1, 1) + r(2, 2)).sqrt();
+            Self::from((r(1, 0) - r(0, 1)) / s, (r(0, 2) + r(2, 0)) / s, (r(1, 2) + r(2, 1)) / s, s / 4.0)
+        };
+        if !(q.norm() > 0.0 && q.norm().is_finite()) {
+            return Err("Matrix is not a rotation".to_string());
+        }
+        // Any matrix yields some q; it is a rotation only if q reproduces it.
+        let rebuilt = q.normalize().to_rotation_matrix();
+        let mut deviation: f32 = 0.0;
+        for i in 0..3 {
+            for j in 0..3 {
+                deviation = deviation.max((rebuilt.data[i][j] - r(i, j)).abs());
+            }
+        }
+        if deviation > ROTATION_TOLERANCE {
+            return Err(format!("Matrix is not a rotation (entries off by {})", deviation));
+        }
+        Ok(q.normalize())
+    }
+
+    /// 3x3 rotation matrix R with R v = `rotate(v)`. Assumes a unit quaternion.
+    pub fn to_rotation_matrix(&self) -> Matrix<f32> {
+        let Self { w, x, y, z } = *self;
+        Matrix::from([
+            [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - w * z), 2.0 * (x * z + w * y)],
+            [2.0 * (x * y + w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - w * x)],
+            [2.0 * (x * z - w * y), 2.0 * (y * z + w * x), 1.0 - 2.0 * (x * x + y * y)],
+        ])
+    }
+
+    /// The rotation as a 4x4 homogeneous transform with no translation.
+    pub fn to_homogeneous(&self) -> Matrix<f32> {
+        let mut m = Matrix::identity(4);
+        let r = self.to_rotation_matrix();
+        for i in 0..3 {
+            m.data[i][..3].copy_from_slice(&r.data[i]);
+        }
+        m
+    }
+
+    /// q v q^-1 for a 3-vector v, without building the matrix. Assumes a
+    /// unit quaternion.
+    pub fn rotate(&self, v: &Vector<f32>) -> Vector<f32> {
+        assert_eq!(v.size(), 3, "Can only rotate 3-vectors, got {} components", v.size());
+        // v + 2w (u x v) + 2 u x (u x v), where u is the vector part.
+        let u = [self.x, self.y, self.z];
+        let cross = |a: [f32; 3], b: [f32; 3]| [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]];
+        let p = [v.data[0], v.data[1], v.data[2]];
+        let t = cross(u, p).map(|c| 2.0 * c);
+        let ut = cross(u, t);
+        Vector::from([0, 1, 2].map(|i| p[i] + self.w * t[i] + ut[i]))
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Self::from(self.w, -self.x, -self.y, -self.z)
+    }
+
+    pub fn dot(&self, other: &Self) -> f32 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn norm_sqr(&self) -> f32 {
+        self.dot(self)
+    }
+
+    pub fn norm(&self) -> f32 {
+        self.norm_sqr().sqrt()
+    }
+
+    /// q* / |q|^2, `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        let n = self.norm_sqr();
+        if n == 0.0 {
+            return None;
+        }
+        Some(self.conjugate() * (1.0 / n))
+    }
+
+    pub fn normalize(&self) -> Self {
+        let n = self.norm();
+        assert!(n > 0.0, "Cannot normalize a zero quaternion");
+        *self * (1.0 / n)
+    }
+
+    /// Constant-speed interpolation along the shorter great arc between two
+    /// unit quaternions. Falls back to `nlerp` when they are nearly equal,
+    /// where sin θ is too small to divide by.
+    pub fn slerp(a: Self, b: Self, t: f32) -> Self {
+        let (b, cos) = shorter_arc(a, b);
+        if cos > 0.9995 {
+            return Self::nlerp(a, b, t);
+        }
+        let theta = cos.acos();
+        let sin = theta.sin();
+        a * (((1.0 - t) * theta).sin() / sin) + b * ((t * theta).sin() / sin)
+    }
+
+    /// Normalized linear interpolation: cheaper than `slerp` and on the same
+    /// path, but not at constant angular speed.
+    pub fn nlerp(a: Self, b: Self, t: f32) -> Self {
+        let (b, _) = shorter_arc(a, b);
+        (a * (1.0 - t) + b * t).normalize()
+    }
+}
+
+// q and -q are the same rotation; flip b into a's hemisphere.
+fn shorter_arc(a: Quaternion, b: Quaternion) -> (Quaternion, f32) {
+    let cos = a.dot(&b);
+    if cos < 0.0 { (-b, -cos) } else { (b, cos) }
+}
+
+impl Lerp for Quaternion {
+    fn lerp(u: Self, v: Self, t: f32) -> Self {
+        Self::nlerp(u, v, t)
+    }
+}
+
+impl Add for Quaternion {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::from(self.w + rhs.w, self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Quaternion {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::from(self.w - rhs.w, self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+/// Hamilton product.
+impl Mul for Quaternion {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::from(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
+
+impl Mul<f32> for Quaternion {
+    type Output = Self;
+    fn mul(self, rhs: f32) -> Self {
+        Self::from(self.w * rhs, self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Div for Quaternion {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        self.mul(rhs.inverse().expect("Quaternion division by zero"))
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::from(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl AddAssign for Quaternion {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+impl SubAssign for Quaternion {
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+impl MulAssign for Quaternion {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl fmt::Display for Quaternion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.w)?;
+        for (part, unit) in [(self.x, 'i'), (self.y, 'j'), (self.z, 'k')] {
+            if part.is_sign_negative() {
+                write!(f, "{}{}", part, unit)?;
+            } else {
+                write!(f, "+{}{}", part, unit)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Semiring for Quaternion {
+    fn zero() -> Self { Self::from(0.0, 0.0, 0.0, 0.0) }
+    fn one() -> Self { Self::identity() }
+}
+
+impl Ring for Quaternion {}
@@ -0,0 +1,278 @@
+use std::fmt;
+use std::cmp::Ordering;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign};
+use super::field::{Semiring, Ring, Field};
+use crate::Matrix;
+
+/// Exact fraction num/den over i64, always stored in lowest terms with den > 0.
+/// Intermediate products are formed in i128; a result that does not fit back
+/// into i64 panics in the operators and is `None` from the `checked_*` methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i64,
+    den: i64,
+}
+
+impl Rational {
+    pub fn new(num: i64, den: i64) -> Self {
+        Self::reduce(num as i128, den as i128).expect("Rational: zero denominator or overflow")
+    }
+
+    pub fn numer(self) -> i64 {
+        self.num
+    }
+
+    pub fn denom(self) -> i64 {
+        self.den
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.den == 1
+    }
+
+    pub fn recip(self) -> Self {
+        Self::new(self.den, self.num)
+    }
+
+    fn reduce(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+        let g = gcd(num, den);
+        let (mut num, mut den) = (num / g, den / g);
+        if den < 0 {
+            num = -num;
+            den = -den;
+        }
+        Some(Self { num: i64::try_from(num).ok()?, den: i64::try_from(den).ok()? })
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (a, b, c, d) = (self.num as i128, self.den as i128, rhs.num as i128, rhs.den as i128);
+        Self::reduce(a * d + c * b, b * d)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(-rhs)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::reduce(self.num as i128 * rhs.num as i128, self.den as i128 * rhs.den as i128)
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        Self::reduce(self.num as i128 * rhs.den as i128, self.den as i128 * rhs.num as i128)
+    }
+
+    /// Best rational approximation with a denominator of at most `max_den`,
+    /// taken from the continued-fraction convergents of `x`.
+    pub fn approximate(x: f64, max_den: i64) -> Self {
+        assert!(x.is_finite(), "Rational: cannot approximate {}", x);
+        let (mut h0, mut h1) = (0i128, 1i128);
+        let (mut k0, mut k1) = (1i128, 0i128);
+        let mut rest = x;
+        loop {
+            let a = rest.floor();
+            if a.abs() > i64::MAX as f64 {
+                break;
+            }
+            let a = a as i128;
+            let (h2, k2) = (a * h1 + h0, a * k1 + k0);
+            if k2 > max_den as i128 || h2.abs() > i64::MAX as i128 {
+                break;
+            }
+            (h0, h1, k0, k1) = (h1, h2, k1, k2);
+            let frac = rest - a as f64;
+            if frac == 0.0 || (h1 as f64 / k1 as f64) == x {
+                break;
+            }
+            rest = 1.0 / frac;
+        }
+        if k1 == 0 {
+            return Self::new(x.round() as i64, 1);
+        }
+        Self::new(h1 as i64, k1 as i64)
+    }
+
+    /// The simplest convergent that converts back to exactly the same f32, so
+    /// 0.1f32 becomes 1/10 rather than 13421773/134217728. Panics for values
+    /// that are not finite or lie beyond the range of i64.
+    pub fn from_f32(x: f32) -> Self {
+        assert!(x.is_finite(), "Rational: cannot convert {}", x);
+        let target = x as f64;
+        let (mut h0, mut h1) = (0i128, 1i128);
+        let (mut k0, mut k1) = (1i128, 0i128);
+        let mut rest = target;
+        for _ in 0..64 {
+            let a = rest.floor();
+            if a.abs() > i64::MAX as f64 {
+                break;
+            }
+            let a = a as i128;
+            let (h2, k2) = (a * h1 + h0, a * k1 + k0);
+            if k2 > i64::MAX as i128 || h2.abs() > i64::MAX as i128 {
+                break;
+            }
+            (h0, h1, k0, k1) = (h1, h2, k1, k2);
+            if (h1 as f64 / k1 as f64) as f32 == x {
+                break;
+            }
+            let frac = rest - a as f64;
+            if frac == 0.0 {
+                break;
+            }
+            rest = 1.0 / frac;
+        }
+        assert!(k1 != 0, "Rational: {} is outside the range of i64", x);
+        Self::new(h1 as i64, k1 as i64)
+    }
+}
+
+pub(crate) fn gcd(mut a: i128, mut b: i128) -> i128 {
+    a = a.abs();
+    b = b.abs();
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    if a == 0 { 1 } else { a }
+}
+
+pub(crate) fn isqrt(n: i64) -> Option<i64> {
+    if n < 0 {
+        return None;
+    }
+    let mut r = (n as f64).sqrt() as i64;
+    while r * r > n {
+        r -= 1;
+    }
+    while (r + 1).checked_mul(r + 1).is_some_and(|sq| sq <= n) {
+        r += 1;
+    }
+    if r * r == n { Some(r) } else { None }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self { num: 0, den: 1 }
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Self { num: n, den: 1 }
+    }
+}
+
+impl From<f32> for Rational {
+    fn from(x: f32) -> Self {
+        Self::from_f32(x)
+    }
+}
+
+impl From<Rational> for f32 {
+    fn from(r: Rational) -> f32 {
+        (r.num as f64 / r.den as f64) as f32
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("Rational overflow in addition")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).expect("Rational overflow in subtraction")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).expect("Rational overflow in multiplication")
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        assert!(rhs.num != 0, "Rational division by zero");
+        self.checked_div(rhs).expect("Rational overflow in division")
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(self.num.checked_neg().expect("Rational overflow in negation"), self.den)
+    }
+}
+
+impl AddAssign for Rational {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+impl SubAssign for Rational {
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+impl MulAssign for Rational {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.num as i128 * other.den as i128).cmp(&(other.num as i128 * self.den as i128))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl Semiring for Rational {
+    fn zero() -> Self { Self { num: 0, den: 1 } }
+    fn one() -> Self { Self { num: 1, den: 1 } }
+}
+
+impl Ring for Rational {}
+
+impl Field for Rational {
+    fn fma(self, b: Self, c: Self) -> Self { self * b + c }
+    fn abs(self) -> Self {
+        if self.num < 0 { -self } else { self }
+    }
+    // Exact for squares of rationals, otherwise the closest fraction with a
+    // denominator below 2^31. Panics on negative input, which has no real root.
+    fn sqrt(self) -> Self {
+        assert!(self.num >= 0, "Error: Cannot take the square root of the negative rational {}!", self);
+        match (isqrt(self.num), isqrt(self.den)) {
+            (Some(n), Some(d)) => Self { num: n, den: d },
+            _ => Self::approximate((self.num as f64 / self.den as f64).sqrt(), i32::MAX as i64),
+        }
+    }
+}
+
+impl Matrix<f32> {
+    /// Lifts every entry to the simplest fraction that rounds to it.
+    pub fn to_rational(&self) -> Matrix<Rational> {
+        Matrix::from(
+            self.data.iter()
+                .map(|row| row.iter().map(|&v| Rational::from_f32(v)).collect::<Vec<Rational>>())
+                .collect::<Vec<Vec<Rational>>>()
+        )
+    }
+}
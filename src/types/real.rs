@@ -0,0 +1,90 @@
+use std::fmt;
+use std::str::FromStr;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
+
+/// The IEEE float types, f32 and f64: the scalars that `Complex` and the
+/// other float-backed types are generic over. Only what those types need is
+/// here, each method forwarding to the inherent one of the same name.
+pub trait Real:
+    Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Copy
+    + PartialEq
+    + PartialOrd
+    + Default
+    + fmt::Debug
+    + fmt::Display
+    + FromStr
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const PI: Self;
+    const EPSILON: Self;
+    const MAX: Self;
+    const MIN_POSITIVE: Self;
+    const INFINITY: Self;
+    const NAN: Self;
+
+    /// Nearest representable value; the way to write constants in generic code.
+    fn from_f64(x: f64) -> Self;
+    fn to_f64(self) -> f64;
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_infinite(self) -> bool;
+    fn is_finite(self) -> bool;
+}
+
+macro_rules! impl_real {
+    ($($t:ident),*) => {$(
+        impl Real for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const PI: Self = std::$t::consts::PI;
+            const EPSILON: Self = $t::EPSILON;
+            const MAX: Self = $t::MAX;
+            const MIN_POSITIVE: Self = $t::MIN_POSITIVE;
+            const INFINITY: Self = $t::INFINITY;
+            const NAN: Self = $t::NAN;
+
+            fn from_f64(x: f64) -> Self { x as $t }
+            fn to_f64(self) -> f64 { self as f64 }
+
+            fn abs(self) -> Self { $t::abs(self) }
+            fn sqrt(self) -> Self { $t::sqrt(self) }
+            fn exp(self) -> Self { $t::exp(self) }
+            fn ln(self) -> Self { $t::ln(self) }
+            fn powf(self, n: Self) -> Self { $t::powf(self, n) }
+            fn sin(self) -> Self { $t::sin(self) }
+            fn cos(self) -> Self { $t::cos(self) }
+            fn sinh(self) -> Self { $t::sinh(self) }
+            fn cosh(self) -> Self { $t::cosh(self) }
+            fn atan2(self, other: Self) -> Self { $t::atan2(self, other) }
+            fn hypot(self, other: Self) -> Self { $t::hypot(self, other) }
+            fn copysign(self, sign: Self) -> Self { $t::copysign(self, sign) }
+            fn is_nan(self) -> bool { $t::is_nan(self) }
+            fn is_infinite(self) -> bool { $t::is_infinite(self) }
+            fn is_finite(self) -> bool { $t::is_finite(self) }
+        }
+    )*};
+}
+
+impl_real!(f32, f64);
@@ -0,0 +1,296 @@
+use super::complex::Complex;
+use super::polynomial::Polynomial;
+use crate::Matrix;
+
+// Francis steps allowed per eigenvalue; exceptional shifts are taken every
+// 10 of them to break cycles.
+const MAX_QR_ITERATIONS: usize = 30;
+
+// Newton steps per root during polishing.
+const MAX_NEWTON_STEPS: usize = 8;
+
+/// Complex roots of a0 + a1 x + ... + an x^n, coefficients lowest degree
+/// first as in `Polynomial::new`, listed with multiplicity and sorted by real
+/// part, then imaginary part.
+///
+/// Zero roots split off as exact zeros. The rest are the eigenvalues of the
+/// balanced companion matrix, found by the Francis double-shift QR
+/// iteration in f64 and then polished by Newton steps on the original
+/// polynomial; a step is kept only while it reduces |p(z)|.
+///
+/// Fails for the zero polynomial, and if the QR iteration does not converge.
+pub fn roots(coeffs: &[f32]) -> Result<Vec<Complex>, String> {
+    let a: Vec<f64> = coeffs.iter().map(|&c| c as f64).collect();
+    let high = a.iter().rposition(|&c| c != 0.0).ok_or("The zero polynomial has no well-defined roots")?;
+    let low = a.iter().position(|&c| c != 0.0).unwrap_or(0);
+    let trimmed = &a[low..=high];
+
+    let mut found: Vec<Complex<f64>> = vec![Complex::from(0.0, 0.0); low];
+    if trimmed.len() > 1 {
+        let companion = companion_matrix(trimmed);
+        found.extend(hessenberg_eigenvalues(&companion)?.into_iter().map(|z| polish(&a, z)));
+    }
+
+    found.sort_by(|x, y| x.re.total_cmp(&y.re).then(x.im.total_cmp(&y.im)));
+    Ok(found.into_iter().map(|z| Complex::from(z.re as f32, z.im as f32)).collect())
+}
+
+impl Polynomial<f32> {
+    /// See `roots`.
+    pub fn roots(&self) -> Result<Vec<Complex>, String> {
+        roots(self.coeffs())
+    }
+}
+
+// The companion matrix of the monic polynomial a / a_n in upper Hessenberg
+// form: -a_(n-1) / a_n ... -a_0 / a_n across the first row, ones below the
+// diagonal.
+fn companion_matrix(a: &[f64]) -> Matrix<f64> {
+    let n = a.len() - 1;
+    let mut m = vec![vec![0.0; n]; n];
+    for j in 0..n {
+        m[0][j] = -a[n - 1 - j] / a[n];
+    }
+    for i in 1..n {
+        m[i][i - 1] = 1.0;
+    }
+    Matrix::from(m)
+}
+
+// Parlett-Reinsch balancing, on a matrix indexed from 1: scales rows and
+// columns by powers of two, which is exact and keeps the eigenvalues, until
+// each row and column have similar norms. Companion matrices of polynomials
+// with widely spread coefficients need it to get their roots to full accuracy.
+#[allow(clippy::needless_range_loop)]
+fn balance(a: &mut [Vec<f64>]) {
+    let n = a.len() - 1;
+    let mut done = false;
+    while !done {
+        done = true;
+        for i in 1..=n {
+            let (mut c, mut r) = (0.0, 0.0);
+            for j in (1..=n).filter(|&j| j != i) {
+                c += a[j][i].abs();
+                r += a[i][j].abs();
+            }
+            if c == 0.0 || r == 0.0 {
+                continue;
+            }
+            let s = c + r;
+            let mut f = 1.0;
+            while c < r / 2.0 {
+                f *= 2.0;
+                c *= 4.0;
+            }
+            while c > r * 2.0 {
+                f /= 2.0;
+                c /= 4.0;
+            }
+            if (c + r) / f < 0.95 * s {
+                done = false;
+                for j in 1..=n {
+                    a[i][j] /= f;
+                    a[j][i] *= f;
+                }
+            }
+        }
+    }
+}
+
+// Eigenvalues of an upper Hessenberg matrix by the Francis double-shift QR
+// iteration after balancing, deflating 1x1 and 2x2 blocks off the bottom as
+// subdiagonal entries become negligible. This is EISPACK's `hqr`, and works
+// on a copy indexed from 1 as it does.
+#[allow(clippy::needless_range_loop)]
+fn hessenberg_eigenvalues(h: &Matrix<f64>) -> Result<Vec<Complex<f64>>, String> {
+    let n = h.rows;
+    let mut a = vec![vec![0.0; n + 1]; n + 1];
+    for i in 0..n {
+        a[i + 1][1..].copy_from_slice(&h.data[i]);
+    }
+    balance(&mut a);
+    let mut eigenvalues = Vec::with_capacity(n);
+    let mut anorm = 0.0;
+    for i in 1..=n {
+        for j in (i.max(2) - 1)..=n {
+            anorm += a[i][j].abs();
+        }
+    }
+
+    let mut nn = n;
+    let mut t = 0.0;
+    while nn >= 1 {
+        let mut its = 0;
+        loop {
+            // Look for a negligible subdiagonal entry a[l][l-1].
+            let mut l = nn;
+            while l >= 2 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+                if s == 0.0 {
+                    s = anorm;
+                }
+                if a[l][l - 1].abs() + s == s {
+                    a[l][l - 1] = 0.0;
+                    break;
+                }
+                l -= 1;
+            }
+
+            let mut x = a[nn][nn];
+            if l == nn {
+                eigenvalues.push(Complex::from(x + t, 0.0));
+                nn -= 1;
+                break;
+            }
+            let mut y = a[nn - 1][nn - 1];
+            let mut w = a[nn][nn - 1] * a[nn - 1][nn];
+            if l == nn - 1 {
+                // A 2x2 block: its two eigenvalues in closed form.
+                let p = 0.5 * (y - x);
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x += t;
+                if q >= 0.0 {
+                    let z = p + z.copysign(p);
+                    let second = if z != 0.0 { x - w / z } else { x + z };
+                    eigenvalues.push(Complex::from(x + z, 0.0));
+                    eigenvalues.push(Complex::from(second, 0.0));
+                } else {
+                    eigenvalues.push(Complex::from(x + p, z));
+                    eigenvalues.push(Complex::from(x + p, -z));
+                }
+                nn -= 2;
+                break;
+            }
+
+            if its == MAX_QR_ITERATIONS {
+                return Err(format!("QR iteration did not converge within {} iterations", MAX_QR_ITERATIONS));
+            }
+            if its == 10 || its == 20 {
+                // Exceptional shift.
+                t += x;
+                for i in 1..=nn {
+                    a[i][i] -= x;
+                }
+                let s = a[nn][nn - 1].abs() + a[nn - 1][nn - 2].abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+            its += 1;
+
+            // Find two consecutive small subdiagonal entries to start the
+            // bulge from.
+            let (mut p, mut q, mut r): (f64, f64, f64);
+            let mut m = nn - 2;
+            loop {
+                let z = a[m][m];
+                let rr = x - z;
+                let ss = y - z;
+                p = (rr * ss - w) / a[m + 1][m] + a[m][m + 1];
+                q = a[m + 1][m + 1] - z - rr - ss;
+                r = a[m + 2][m + 1];
+                let s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                if u + v == v {
+                    break;
+                }
+                m -= 1;
+            }
+            for i in m + 2..=nn {
+                a[i][i - 2] = 0.0;
+                if i != m + 2 {
+                    a[i][i - 3] = 0.0;
+                }
+            }
+
+            // Chase the bulge down with Householder reflections.
+            for k in m..nn {
+                if k != m {
+                    p = a[k][k - 1];
+                    q = a[k + 1][k - 1];
+                    r = if k != nn - 1 { a[k + 2][k - 1] } else { 0.0 };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != 0.0 {
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                }
+                let s = (p * p + q * q + r * r).sqrt().copysign(p);
+                if s == 0.0 {
+                    continue;
+                }
+                if k == m {
+                    if l != m {
+                        a[k][k - 1] = -a[k][k - 1];
+                    }
+                } else {
+                    a[k][k - 1] = -s * x;
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q /= p;
+                r /= p;
+                for j in k..=nn {
+                    let mut pj = a[k][j] + q * a[k + 1][j];
+                    if k != nn - 1 {
+                        pj += r * a[k + 2][j];
+                        a[k + 2][j] -= pj * z;
+                    }
+                    a[k + 1][j] -= pj * y;
+                    a[k][j] -= pj * x;
+                }
+                for i in l..=nn.min(k + 3) {
+                    let mut pi = x * a[i][k] + y * a[i][k + 1];
+                    if k != nn - 1 {
+                        pi += z * a[i][k + 2];
+                        a[i][k + 2] -= pi * r;
+                    }
+                    a[i][k + 1] -= pi * q;
+                    a[i][k] -= pi;
+                }
+            }
+            if l >= nn - 1 {
+                break;
+            }
+        }
+    }
+    Ok(eigenvalues)
+}
+
+// Newton's method on the original coefficients (lowest degree first),
+// stopping as soon as a step fails to reduce |p(z)|.
+fn polish(a: &[f64], mut z: Complex<f64>) -> Complex<f64> {
+    let evaluate = |z: Complex<f64>| {
+        let mut p = Complex::from(0.0, 0.0);
+        let mut dp = Complex::from(0.0, 0.0);
+        for &c in a.iter().rev() {
+            dp = dp * z + p;
+            p = p * z + Complex::from(c, 0.0);
+        }
+        (p, dp)
+    };
+    let (mut p, mut dp) = evaluate(z);
+    for _ in 0..MAX_NEWTON_STEPS {
+        if p.modulus() == 0.0 || dp.modulus() == 0.0 {
+            break;
+        }
+        let next = z - p / dp;
+        let (next_p, next_dp) = evaluate(next);
+        if next_p.modulus() >= p.modulus() {
+            break;
+        }
+        (z, p, dp) = (next, next_p, next_dp);
+    }
+    z
+}
@@ -0,0 +1,152 @@
+use std::fmt;
+use super::field::Field;
+use crate::Matrix;
+
+/// One elementary row operation. Rows are 0-based here and 1-based when displayed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RowOp<K: Field> {
+    /// R_a <-> R_b
+    Swap(usize, usize),
+    /// R_row <- factor * R_row
+    Scale(usize, K),
+    /// R_row <- R_row / divisor
+    Divide(usize, K),
+    /// R_target <- R_target + factor * R_source
+    AddMultiple { target: usize, source: usize, factor: K },
+}
+
+impl<K: Field> RowOp<K> {
+    pub fn apply(&self, m: &mut Matrix<K>) {
+        match self {
+            RowOp::Swap(a, b) => m.swap_rows(*a, *b),
+            RowOp::Scale(row, factor) => m.scale_row(*row, factor.clone()),
+            RowOp::Divide(row, divisor) => m.divide_row(*row, divisor.clone()),
+            RowOp::AddMultiple { target, source, factor } => m.add_row_multiple(*target, *source, factor.clone()),
+        }
+    }
+
+    /// The n x n matrix E with E A equal to this operation applied to A.
+    pub fn elementary_matrix(&self, n: usize) -> Matrix<K> {
+        let mut e = Matrix::identity(n);
+        self.apply(&mut e);
+        e
+    }
+
+    /// The operation that undoes this one.
+    pub fn inverse(&self) -> RowOp<K> {
+        match self {
+            RowOp::Swap(a, b) => RowOp::Swap(*a, *b),
+            RowOp::Scale(row, factor) => RowOp::Scale(*row, K::one() / factor.clone()),
+            RowOp::Divide(row, divisor) => RowOp::Scale(*row, divisor.clone()),
+            RowOp::AddMultiple { target, source, factor } => RowOp::AddMultiple { target: *target, source: *source, factor: -factor.clone() },
+        }
+    }
+}
+
+impl<K: Field> fmt::Display for RowOp<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RowOp::Swap(a, b) => write!(f, "R{} ↔ R{}", a + 1, b + 1),
+            RowOp::Scale(row, factor) => write!(f, "R{} ← {}·R{}", row + 1, factor, row + 1),
+            RowOp::Divide(row, divisor) => write!(f, "R{} ← R{} / {}", row + 1, row + 1, divisor),
+            // K need not be ordered, so a negative factor is recognised by its
+            // text; one with an inner sign, such as a complex number, is kept whole.
+            RowOp::AddMultiple { target, source, factor } => {
+                let text = factor.to_string();
+                match text.strip_prefix('-') {
+                    Some(rest) if !rest.contains(['+', '-']) => {
+                        write!(f, "R{} ← R{} − {}·R{}", target + 1, target + 1, rest, source + 1)
+                    }
+                    _ => write!(f, "R{} ← R{} + {}·R{}", target + 1, target + 1, text, source + 1),
+                }
+            }
+        }
+    }
+}
+
+/// Opt-in record of the row operations an elimination performed, in order.
+/// Pass one to `row_echelon_with_log`, `determinant_with_log` or `inverse_with_log`.
+/// A log made with `with_snapshots` also keeps the matrix after every operation.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RowOpLog<K: Field> {
+    pub ops: Vec<RowOp<K>>,
+    pub snapshots: Vec<Matrix<K>>,
+    keep_snapshots: bool,
+}
+
+impl<K: Field> RowOpLog<K> {
+    pub fn new() -> Self {
+        Self { ops: Vec::new(), snapshots: Vec::new(), keep_snapshots: false }
+    }
+
+    pub fn with_snapshots() -> Self {
+        Self { ops: Vec::new(), snapshots: Vec::new(), keep_snapshots: true }
+    }
+
+    pub fn push(&mut self, op: RowOp<K>) {
+        self.ops.push(op);
+    }
+
+    pub fn record(&mut self, op: RowOp<K>, after: &Matrix<K>) {
+        self.ops.push(op);
+        if self.keep_snapshots {
+            self.snapshots.push(after.clone());
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.ops.clear();
+        self.snapshots.clear();
+    }
+
+    pub fn elementary_matrices(&self, n: usize) -> Vec<Matrix<K>> {
+        self.ops.iter().map(|op| op.elementary_matrix(n)).collect()
+    }
+
+    /// E_k ... E_2 E_1: the single matrix that takes the original matrix to the
+    /// logged result. For `inverse_with_log` this is the inverse itself.
+    pub fn transform(&self, n: usize) -> Matrix<K> {
+        let mut result = Matrix::identity(n);
+        for op in &self.ops {
+            op.apply(&mut result);
+        }
+        result
+    }
+
+    /// Applies the logged operations, in order, to a copy of `m`.
+    pub fn replay(&self, m: &Matrix<K>) -> Matrix<K> {
+        let mut result = m.clone();
+        for op in &self.ops {
+            op.apply(&mut result);
+        }
+        result
+    }
+
+    /// Every intermediate matrix of the replay, starting with `m` itself.
+    pub fn steps(&self, m: &Matrix<K>) -> Vec<Matrix<K>> {
+        let mut current = m.clone();
+        let mut steps = vec![current.clone()];
+        for op in &self.ops {
+            op.apply(&mut current);
+            steps.push(current.clone());
+        }
+        steps
+    }
+}
+
+impl<K: Field> fmt::Display for RowOpLog<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, op) in self.ops.iter().enumerate() {
+            writeln!(f, "{}. {}", i + 1, op)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt;
+use std::ops::{Add, Mul, AddAssign, MulAssign};
+use super::field::Semiring;
+
+/// Min-plus (tropical) number: `a + b` is min(a, b) and `a * b` is a + b.
+/// Zero is +inf (no path), one is 0 (the empty path), so the matrix product
+/// relaxes paths and the k-th power holds shortest walks of k edges.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MinPlus<T = f32>(pub T);
+
+/// Max-plus number: `a + b` is max(a, b) and `a * b` is a + b. Zero is -inf,
+/// one is 0; powers of a DAG's matrix give its longest paths.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MaxPlus<T = f32>(pub T);
+
+impl<T: PartialOrd + Copy> Add for MinPlus<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        if rhs.0 < self.0 { rhs } else { self }
+    }
+}
+
+impl<T: Add<Output = T> + Copy> Mul for MinPlus<T> {
+    type Output = Self;
+    // Tropical multiplication is ordinary addition.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        MinPlus(self.0 + rhs.0)
+    }
+}
+
+impl<T: PartialOrd + Copy> Add for MaxPlus<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        if rhs.0 > self.0 { rhs } else { self }
+    }
+}
+
+impl<T: Add<Output = T> + Copy> Mul for MaxPlus<T> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        MaxPlus(self.0 + rhs.0)
+    }
+}
+
+impl<T: PartialOrd + Copy> AddAssign for MinPlus<T> {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+impl<T: Add<Output = T> + Copy> MulAssign for MinPlus<T> {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+impl<T: PartialOrd + Copy> AddAssign for MaxPlus<T> {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+impl<T: Add<Output = T> + Copy> MulAssign for MaxPlus<T> {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl<T: fmt::Display> fmt::Display for MinPlus<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for MaxPlus<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+macro_rules! impl_tropical {
+    ($($t:ty),*) => {$(
+        impl Semiring for MinPlus<$t> {
+            fn zero() -> Self { MinPlus(<$t>::INFINITY) }
+            fn one() -> Self { MinPlus(0.0) }
+        }
+
+        impl Semiring for MaxPlus<$t> {
+            fn zero() -> Self { MaxPlus(<$t>::NEG_INFINITY) }
+            fn one() -> Self { MaxPlus(0.0) }
+        }
+    )*};
+}
+
+impl_tropical!(f32, f64);
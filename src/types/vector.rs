@@ -1,14 +1,15 @@
 use std::fmt;
-use super::field::Field;
+use super::field::{Semiring, Ring, Field};
+use crate::Matrix;
 
 
 #[derive(Debug, Clone,PartialEq,)]
 
-pub struct Vector<K : Field>{
+pub struct Vector<K : Semiring>{
     pub data: Vec<K>,
 }
 
-impl<K: Field> Vector<K>{
+impl<K: Semiring> Vector<K>{
     pub fn from(data:impl Into<Vec<K>>) -> Self{
     
         Self { data: data.into()}
@@ -27,38 +28,49 @@ impl<K: Field> Vector<K>{
 
     pub fn add(&mut self, v: Vector<K>){
         for i in 0..self.data.len(){
-            self.data[i] += v.data[i]
+            self.data[i] += v.data[i].clone()
         }
     }
 
-    pub fn sub(&mut self, v: Vector<K>){
-        for i in 0..self.data.len(){
-            self.data[i] -= v.data[i]
-        }
-    }
-    
     pub fn scl(&mut self, a: K){
         for i in 0..self.data.len(){
-            self.data[i] *= a
+            self.data[i] *= a.clone()
         }
     }
 
     pub fn dot(&self, v: Vector<K>) -> K{
-        let mut result = K::zero();
+        K::sum_of_products(self.data.iter().zip(&v.data).map(|(a, b)| (a.clone(), b.clone())))
+    }
+    
+    #[allow(clippy::needless_range_loop)]
+    pub fn outer(&self, v: &Vector<K>) -> Matrix<K>{
+        let mut result = vec![vec![K::zero(); v.data.len()]; self.data.len()];
 
         for i in 0..self.data.len(){
-            result += self.data[i] * v.data[i]; 
+            for j in 0..v.data.len(){
+                result[i][j] = self.data[i].clone() * v.data[j].clone();
+            }
         }
 
-        result
+        Matrix::from(result)
     }
-    
+}
+
+impl<K: Ring> Vector<K>{
+    pub fn sub(&mut self, v: Vector<K>){
+        for i in 0..self.data.len(){
+            self.data[i] -= v.data[i].clone()
+        }
+    }
+}
+
+impl<K: Field> Vector<K>{
     pub fn norm_1(&self) -> f32
     {
         let mut result = 0.0f32;
         for i in 0..self.data.len(){
 
-            let val_f32: f32 = self.data[i].into(); 
+            let val_f32: f32 = self.data[i].clone().into(); 
             result += val_f32.abs();
         }
         result
@@ -69,18 +81,24 @@ impl<K: Field> Vector<K>{
         let mut result = 0.0f32;
         for i in 0..self.data.len(){
 
-            let val_f32: f32 = self.data[i].into(); 
+            let val_f32: f32 = self.data[i].clone().into(); 
             result += val_f32.powf(2.0);
         }
         result.powf(0.5)
     }
 
+    /// The Euclidean norm computed in K rather than f32, so it keeps what
+    /// K carries: the derivative for `Dual`, the precision of f64-backed types.
+    pub fn euclidean_norm(&self) -> K {
+        self.dot(self.clone()).sqrt()
+    }
+
     pub fn norm_inf(&self) -> f32
     {
         let mut result = 0.0f32;
         for i in 0..self.data.len(){
 
-            let val_f32: f32 = self.data[i].into();
+            let val_f32: f32 = self.data[i].clone().into();
             let abs_val = val_f32.abs(); 
             result = abs_val.max(result);
         }
@@ -88,12 +106,12 @@ impl<K: Field> Vector<K>{
     }
 }
 
-pub fn linear_combination<K : Field>(u: &[Vector<K>], coefs: &[K]) -> Vector<K>{
+pub fn linear_combination<K : Semiring>(u: &[Vector<K>], coefs: &[K]) -> Vector<K>{
     let mut reusult = Vector::from(vec![K::zero(); u[0].data.len()]);
 
     for i in 0..u.len(){
         let mut scl_vectot = u[i].clone();
-        scl_vectot.scl(coefs[i]);
+        scl_vectot.scl(coefs[i].clone());
         reusult.add(scl_vectot);
     }
 
@@ -108,17 +126,17 @@ pub fn angle_cos<K: Field>(u: &Vector<K>, v: &Vector<K>) -> f32
     dot / (norm_u * norm_v)
 }
 
-pub fn cross_product<K: Field>(u: &Vector<K>, v: &Vector<K>) -> Vector<K>{
+pub fn cross_product<K: Ring>(u: &Vector<K>, v: &Vector<K>) -> Vector<K>{
 
-    let s1 = u.data[1].mul(v.data[2]).sub(u.data[2].mul(v.data[1]));
-    let s2 = u.data[2].mul(v.data[0]).sub(u.data[0].mul(v.data[2]));
-    let s3 = u.data[0].mul(v.data[1]).sub(u.data[1].mul(v.data[0]));
+    let s1 = u.data[1].clone().mul(v.data[2].clone()).sub(u.data[2].clone().mul(v.data[1].clone()));
+    let s2 = u.data[2].clone().mul(v.data[0].clone()).sub(u.data[0].clone().mul(v.data[2].clone()));
+    let s3 = u.data[0].clone().mul(v.data[1].clone()).sub(u.data[1].clone().mul(v.data[0].clone()));
     
     Vector::from([s1,s2,s3])
 
 }
 
-impl<K: Field> fmt::Display for Vector<K> {
+impl<K: Semiring> fmt::Display for Vector<K> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for val in &self.data {
             writeln!(f, "[{}]", val)?;